@@ -0,0 +1,626 @@
+use aoc::input::Input;
+use day_02::rules::{Rules, DEFAULT_RULES};
+use day_02::Interpretation;
+use day_03::alphabet::{Alphabet, DEFAULT_RANGES};
+use day_05::crane::{parse_crane, Stacks};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use std::collections::HashMap;
+
+// Python bindings for the solvers that have a library, e.g. from a notebook:
+//
+//     import aoc_py
+//     aoc_py.day07_solve(open("input").read())
+//
+// The inputs are passed as text. Errors the solvers report raise a `ValueError`, and other
+// invalid inputs a `PanicException` like they make the solvers panic.
+
+// Positions as (x, y) and cubes as (x, y, z) tuples, assignments as (start, end) sections and
+// crate moves as (amount, from, to).
+type Point = (usize, usize);
+type Position = (i64, i64);
+type Cube = (i32, i32, i32);
+type Sections = (i64, i64);
+type CrateMove = (usize, usize, usize);
+
+fn value_error(e: impl ToString) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+fn input(text: &str) -> Input {
+    Input::from_bytes(text.as_bytes().to_vec()).unwrap()
+}
+
+// The elves as dicts with their index from 1, number of items and calories.
+#[pyfunction]
+fn day01_parse<'py>(py: Python<'py>, text: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    day_01::parse_elves(&input(text))
+        .map_err(value_error)?
+        .into_iter()
+        .map(|elf| {
+            let dict = PyDict::new(py);
+            dict.set_item("index", elf.index)?;
+            dict.set_item("items", elf.items)?;
+            dict.set_item("calories", elf.calories)?;
+            Ok(dict)
+        })
+        .collect()
+}
+
+#[pyfunction]
+fn day01_solve(text: &str) -> PyResult<(i64, i64)> {
+    let elves = day_01::parse_elves(&input(text)).map_err(value_error)?;
+    let top = day_01::top_elves(&elves, 3);
+    Ok((
+        top.first().map_or(0, |elf| elf.calories),
+        top.iter().map(|elf| elf.calories).sum(),
+    ))
+}
+
+// The rounds as the opponent's move and our letter, with the default rules.
+#[pyfunction]
+fn day02_parse(text: &str) -> PyResult<Vec<(String, String)>> {
+    let rules = Rules::parse(DEFAULT_RULES).unwrap();
+    let input = input(text);
+    let guide = day_02::parse_guide(&rules, &input).map_err(value_error)?;
+    Ok(guide
+        .into_iter()
+        .map(|(opponent, letter)| (rules.name(opponent).to_string(), letter.to_string()))
+        .collect())
+}
+
+#[pyfunction]
+fn day02_solve(text: &str) -> PyResult<(i64, i64)> {
+    let rules = Rules::parse(DEFAULT_RULES).unwrap();
+    let input = input(text);
+    let total = |interpretation| -> PyResult<i64> {
+        let rounds = day_02::play_guide(&rules, &input, interpretation).map_err(value_error)?;
+        Ok(rounds.iter().map(|round| round.score(&rules)).sum())
+    };
+    Ok((
+        total(Interpretation::Move)?,
+        total(Interpretation::Outcome)?,
+    ))
+}
+
+// The item in both compartments of every rucksack.
+#[pyfunction]
+fn day03_parse(text: &str) -> PyResult<Vec<char>> {
+    let alphabet = Alphabet::from_ranges(DEFAULT_RANGES).unwrap();
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|rucksack| {
+            let item = day_03::misplaced_item(&alphabet, rucksack, 2).map_err(value_error)?;
+            Ok(alphabet.item(item))
+        })
+        .collect()
+}
+
+#[pyfunction]
+fn day03_solve(text: &str) -> PyResult<(i64, i64)> {
+    let alphabet = Alphabet::from_ranges(DEFAULT_RANGES).unwrap();
+    let rucksacks = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>();
+    let mut part1 = 0;
+    for rucksack in &rucksacks {
+        let item = day_03::misplaced_item(&alphabet, rucksack, 2).map_err(value_error)?;
+        part1 += alphabet.priority(item);
+    }
+    let mut part2 = 0;
+    for group in rucksacks.chunks(3) {
+        let item = day_03::badge(&alphabet, group).map_err(value_error)?;
+        part2 += alphabet.priority(item);
+    }
+    Ok((part1, part2))
+}
+
+// The pairs of assignments.
+#[pyfunction]
+fn day04_parse(text: &str) -> PyResult<Vec<(Sections, Sections)>> {
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (x, y) = day_04::parse_pair(line).map_err(value_error)?;
+            Ok(((x.start, x.end), (y.start, y.end)))
+        })
+        .collect()
+}
+
+#[pyfunction]
+fn day04_solve(text: &str) -> PyResult<(usize, usize)> {
+    let (mut contained, mut overlapping) = (0, 0);
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        let (x, y) = day_04::parse_pair(line).map_err(value_error)?;
+        if x.contains(&y) || y.contains(&x) {
+            contained += 1;
+        }
+        if x.overlaps(&y) {
+            overlapping += 1;
+        }
+    }
+    Ok((contained, overlapping))
+}
+
+// The drawing and the moves below it.
+fn day05_input(text: &str) -> PyResult<(Stacks, Vec<&str>)> {
+    let lines = text.lines().collect::<Vec<_>>();
+    let blank = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .unwrap_or(lines.len());
+    let stacks = day_05::drawing::parse(&lines[..blank]).map_err(value_error)?;
+    Ok((stacks, lines[(blank + 1).min(lines.len())..].to_vec()))
+}
+
+// The stacks from bottom to top, and the moves.
+#[pyfunction]
+fn day05_parse(text: &str) -> PyResult<(Vec<String>, Vec<CrateMove>)> {
+    let (stacks, lines) = day05_input(text)?;
+    let moves = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            day_05::moves::parse_move(line.as_bytes())
+                .ok_or_else(|| value_error(format!("Invalid move {:?}", line)))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let stacks = stacks.iter().map(|stack| stack.iter().collect()).collect();
+    Ok((stacks, moves))
+}
+
+// The crates on top of the stacks, moved by the 9000 and the 9001.
+#[pyfunction]
+fn day05_solve(text: &str) -> PyResult<(String, String)> {
+    let (stacks, lines) = day05_input(text)?;
+    let (moves, bad) = day_05::moves::check_moves(&stacks, lines.iter().map(|l| l.as_bytes()));
+    if let Some(bad) = bad.first() {
+        return Err(value_error(format!(
+            "Move {}: {}",
+            bad.index + 1,
+            bad.error
+        )));
+    }
+
+    let moves = moves.into_iter().map(|(_, m)| m).collect::<Vec<_>>();
+    let mut cranes = vec![parse_crane("9000").unwrap(), parse_crane("9001").unwrap()];
+    let tops = day_05::moves::run_parallel(&stacks, &moves, &mut cranes)
+        .iter()
+        .map(|stacks| stacks.iter().filter_map(|s| s.last()).collect::<String>())
+        .collect::<Vec<_>>();
+    Ok((tops[0].clone(), tops[1].clone()))
+}
+
+#[pyfunction]
+fn day06_solve(text: &str) -> PyResult<(usize, usize)> {
+    let markers = day_06::marker::find_markers(text.as_bytes(), &[4, 14]).unwrap();
+    match markers[..] {
+        [Some(part1), Some(part2)] => Ok((part1, part2)),
+        _ => Err(value_error("No marker found")),
+    }
+}
+
+// The total size of every directory by absolute path.
+#[pyfunction]
+fn day07_directory_sizes(text: &str) -> HashMap<String, i64> {
+    day_07::directory_sizes(text.lines())
+}
+
+#[pyfunction]
+fn day07_solve(text: &str) -> (i64, i64) {
+    let directories = day_07::directory_sizes(text.lines());
+    (
+        day_07::small_directories_total(&directories),
+        day_07::directory_to_delete(&directories),
+    )
+}
+
+// The heights of the trees by row.
+#[pyfunction]
+fn day08_parse(text: &str) -> Vec<Vec<i64>> {
+    day_08::parse_map(text.lines())
+}
+
+#[pyfunction]
+fn day08_solve(text: &str) -> (usize, i64) {
+    let map = day_08::parse_map(text.lines());
+    (
+        day_08::visible_tree_count(&map),
+        day_08::max_scenic_score(&map),
+    )
+}
+
+// The moves as a direction letter and a number of steps.
+#[pyfunction]
+fn day09_parse(text: &str) -> Vec<(char, i64)> {
+    text.lines().map(day_09_2::parse_move).collect()
+}
+
+// Part 1 has 2 knots, part 2 has 10.
+#[pyfunction]
+fn day09_solve(text: &str) -> (usize, usize) {
+    let moves = day09_parse(text);
+    (
+        day_09_2::tail_visits(&moves, 2).len(),
+        day_09_2::tail_visits(&moves, 10).len(),
+    )
+}
+
+// The value of x during every cycle, the first one being cycle 1.
+#[pyfunction]
+fn day10_parse(text: &str) -> Vec<i64> {
+    day_10_2::x_during_cycles(text.lines())
+}
+
+// Part 2 is the screen, as lines of `#` and `.`.
+#[pyfunction]
+fn day10_solve(text: &str) -> (i64, String) {
+    let xs = day_10_2::x_during_cycles(text.lines());
+    (
+        day_10_2::signal_strength(&xs),
+        day_10_2::render_crt(&day_10_2::draw_crt(&xs)),
+    )
+}
+
+// The monkeys as dicts with their worry levels, test divisor and the monkeys they throw to.
+#[pyfunction]
+fn day11_parse<'py>(py: Python<'py>, text: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    day_11_2::parse_monkeys(&mut text.lines())
+        .map_err(value_error)?
+        .into_iter()
+        .map(|monkey| {
+            let dict = PyDict::new(py);
+            let items = monkey.items.iter().map(|(_, worry)| *worry);
+            dict.set_item("items", items.collect::<Vec<_>>())?;
+            dict.set_item("test_divisor", monkey.test_divisor)?;
+            dict.set_item("if_true", monkey.true_monkey_id)?;
+            dict.set_item("if_false", monkey.false_monkey_id)?;
+            Ok(dict)
+        })
+        .collect()
+}
+
+// Part 1 is 20 rounds with relief, part 2 10000 rounds without.
+#[pyfunction]
+fn day11_solve(text: &str) -> PyResult<(i64, i64)> {
+    let monkeys = || day_11_2::parse_monkeys(&mut text.lines()).map_err(value_error);
+    Ok((
+        day_11_2::monkey_business(monkeys()?, 20, true, |_| ()),
+        day_11_2::monkey_business(monkeys()?, 10000, false, |_| ()),
+    ))
+}
+
+// The heights by row, and the (x, y) start and end positions.
+#[pyfunction]
+fn day12_parse(text: &str) -> (Vec<Vec<usize>>, Point, Point) {
+    let (map, start, end) = day_12::parse_map(text.lines());
+    (map, (start.x, start.y), (end.x, end.y))
+}
+
+// The number of steps from every (x, y) position to the end, unreachable positions excluded.
+#[pyfunction]
+fn day12_distances(text: &str) -> HashMap<Point, usize> {
+    let (map, _, end) = day_12::parse_map(text.lines());
+    day_12::distances_to_end(&map, end)
+        .into_iter()
+        .map(|(pos, node)| ((pos.x, pos.y), node.distance))
+        .collect()
+}
+
+#[pyfunction]
+fn day12_solve(text: &str) -> (usize, usize) {
+    let (map, start, end) = day_12::parse_map(text.lines());
+    let distances = day_12::distances_to_end(&map, end);
+    (
+        distances[&start].distance,
+        day_12::lowest_start_distance(&map, &distances),
+    )
+}
+
+fn day13_value(py: Python<'_>, value: &day_13::Value) -> PyResult<PyObject> {
+    Ok(match value {
+        day_13::Value::Number(n) => n.into_pyobject(py)?.into_any().unbind(),
+        day_13::Value::List(values) => {
+            let values = values
+                .iter()
+                .map(|v| day13_value(py, v))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, values)?.into_any().unbind()
+        }
+    })
+}
+
+// The packets as nested lists, empty lines left out.
+#[pyfunction]
+fn day13_parse(py: Python<'_>, text: &str) -> PyResult<Vec<PyObject>> {
+    text.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| day13_value(py, &day_13::parse_list(line)))
+        .collect()
+}
+
+#[pyfunction]
+fn day13_solve(text: &str) -> (usize, usize) {
+    let packets = day_13::sort_packets(text.lines());
+    (
+        day_13::right_order_sum(text.lines()),
+        day_13::decoder_key(&packets),
+    )
+}
+
+// The rock paths, as (x, y) points.
+#[pyfunction]
+fn day14_parse(text: &str) -> Vec<Vec<(i64, i64)>> {
+    day_14_2::parse_paths(text.lines())
+}
+
+// Part 1 has no floor, part 2 a floor 2 below the lowest rock.
+#[pyfunction]
+fn day14_solve(text: &str) -> (usize, usize) {
+    let paths = day_14_2::parse_paths(text.lines());
+    (
+        day_14_2::resting_sand(&paths, false),
+        day_14_2::resting_sand(&paths, true),
+    )
+}
+
+// The sensors with the distance to their closest beacon, and the beacons.
+#[pyfunction]
+fn day15_parse(text: &str) -> (HashMap<Position, i64>, Vec<Position>) {
+    let (sensors, beacons) = day_15_2::parse_sensors(text.lines());
+    (
+        sensors
+            .into_iter()
+            .map(|(pos, distance)| ((pos.x, pos.y), distance))
+            .collect(),
+        beacons.into_iter().map(|pos| (pos.x, pos.y)).collect(),
+    )
+}
+
+// The row of part 1 and the largest coordinate of part 2 are 10 and 20 for the example.
+#[pyfunction]
+#[pyo3(signature = (text, row = 2000000, max = 4000000))]
+fn day15_solve(text: &str, row: i64, max: i64) -> PyResult<(i64, i64)> {
+    let (sensors, beacons) = day_15_2::parse_sensors(text.lines());
+    let frequency = day_15_2::tuning_frequency(&sensors, &beacons, max)
+        .ok_or_else(|| value_error("No position found for the distress beacon"))?;
+    Ok((
+        day_15_2::no_beacon_count(&sensors, &beacons, row),
+        frequency,
+    ))
+}
+
+// The valves as dicts with their name, rate and connections.
+#[pyfunction]
+fn day16_parse<'py>(py: Python<'py>, text: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    day_16_2::parse_input(text)
+        .into_iter()
+        .map(|valve| {
+            let dict = PyDict::new(py);
+            dict.set_item("name", valve.name)?;
+            dict.set_item("rate", valve.rate)?;
+            dict.set_item("connections", valve.connections)?;
+            Ok(dict)
+        })
+        .collect()
+}
+
+// Every path from AA in the given minutes, as dicts with the valves opened and their score.
+#[pyfunction]
+#[pyo3(signature = (text, minutes = 26))]
+fn day16_permutations<'py>(
+    py: Python<'py>,
+    text: &str,
+    minutes: i64,
+) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    let valves = day_16_2::parse_input(text);
+    let (permutations, valve_aa) = day_16_2::permutations(&valves, minutes);
+
+    permutations
+        .iter()
+        .map(|permutation| {
+            let opened = valves
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != valve_aa && permutation.permutation & (1 << i) != 0)
+                .map(|(_, valve)| valve.name.as_str())
+                .collect::<Vec<_>>();
+
+            let dict = PyDict::new(py);
+            dict.set_item("valves", opened)?;
+            dict.set_item("score", permutation.score)?;
+            Ok(dict)
+        })
+        .collect()
+}
+
+// Part 1 is the best path alone in 30 minutes, part 2 the best pair of paths in 26 minutes.
+#[pyfunction]
+fn day16_solve(text: &str) -> (i64, i64) {
+    let valves = day_16_2::parse_input(text);
+    let (alone, _) = day_16_2::permutations(&valves, 30);
+    let (with_elephant, valve_aa) = day_16_2::permutations(&valves, 26);
+    (
+        alone.iter().map(|p| p.score).max().unwrap_or(0),
+        day_16_2::best_pair_score(&with_elephant, valve_aa),
+    )
+}
+
+// The jets as -1 for `<` and 1 for `>`.
+#[pyfunction]
+fn day17_parse(text: &str) -> Vec<i64> {
+    day_17_1::parse_jets(text.lines().next().unwrap_or(""))
+}
+
+#[pyfunction]
+fn day17_solve(text: &str) -> (i64, i64) {
+    let jets = day17_parse(text);
+    (
+        day_17_1::tower_height(&jets, 2022, |_| ()),
+        day_17_1::tower_height(&jets, 1000000000000, |_| ()),
+    )
+}
+
+#[pyfunction]
+fn day18_parse(text: &str) -> Vec<Cube> {
+    day_18_1::parse_cubes(text)
+        .into_iter()
+        .map(|pos| (pos.x, pos.y, pos.z))
+        .collect()
+}
+
+// The faces reachable from outside, as a cube and the direction its face is looking at.
+#[pyfunction]
+fn day18_exterior_faces(text: &str) -> Vec<(Cube, Cube)> {
+    let cubes = day_18_1::parse_cubes(text);
+    day_18_1::exterior_faces(&cubes)
+        .into_iter()
+        .map(|(pos, dir)| ((pos.x, pos.y, pos.z), (dir.x, dir.y, dir.z)))
+        .collect()
+}
+
+#[pyfunction]
+fn day18_solve(text: &str) -> (usize, usize) {
+    let cubes = day_18_1::parse_cubes(text);
+    (
+        day_18_1::surface_faces(&cubes).len(),
+        day_18_1::exterior_faces(&cubes).len(),
+    )
+}
+
+// The blueprints as dicts with their id and the costs of every robot.
+#[pyfunction]
+fn day19_parse<'py>(py: Python<'py>, text: &str) -> PyResult<Vec<Bound<'py, PyDict>>> {
+    day_19_2::parse_blueprints(text.lines())
+        .map_err(value_error)?
+        .into_iter()
+        .map(|blueprint| {
+            let dict = PyDict::new(py);
+            dict.set_item("id", blueprint.id)?;
+            dict.set_item("ore_robot", (blueprint.ore_robot_ore_cost, 0, 0))?;
+            dict.set_item("clay_robot", (blueprint.clay_robot_ore_cost, 0, 0))?;
+            let obsidian_robot = (
+                blueprint.obsidian_robot_ore_cost,
+                blueprint.obsidian_robot_clay_cost,
+                0,
+            );
+            dict.set_item("obsidian_robot", obsidian_robot)?;
+            let geode_robot = (
+                blueprint.geode_robot_ore_cost,
+                0,
+                blueprint.geode_robot_obsidian_cost,
+            );
+            dict.set_item("geode_robot", geode_robot)?;
+            Ok(dict)
+        })
+        .collect()
+}
+
+// Part 1 adds the quality levels in 24 minutes, part 2 multiplies the geodes of the first 3
+// blueprints in 32 minutes.
+#[pyfunction]
+fn day19_solve(text: &str) -> PyResult<(i32, i32)> {
+    let blueprints = day_19_2::parse_blueprints(text.lines()).map_err(value_error)?;
+    Ok((
+        blueprints
+            .iter()
+            .map(|blueprint| blueprint.id * day_19_2::max_geodes(blueprint, 24))
+            .sum(),
+        blueprints
+            .iter()
+            .take(3)
+            .map(|blueprint| day_19_2::max_geodes(blueprint, 32))
+            .product(),
+    ))
+}
+
+#[pyfunction]
+fn day20_parse(text: &str) -> PyResult<Vec<i64>> {
+    day_20_2::parse_numbers(text.lines()).map_err(value_error)
+}
+
+// Part 1 mixes once, part 2 ten times with the decryption key.
+#[pyfunction]
+fn day20_solve(text: &str) -> PyResult<(i64, i64)> {
+    let numbers = day20_parse(text)?;
+    let sum = |key, rounds| {
+        let mixed = day_20_2::mix(&numbers, key, rounds, |_, _, _, _| ());
+        day_20_2::grove_coordinates(&mixed).iter().sum()
+    };
+    Ok((sum(1, 1), sum(day_20_2::DECRYPTION_KEY, 10)))
+}
+
+// The monkeys by name, yelling a number or an operation as (name, operator, name).
+#[pyfunction]
+fn day21_parse<'py>(py: Python<'py>, text: &str) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (name, statement) in day_21_1::parse_monkeys(text.lines()) {
+        match statement {
+            day_21_1::Statement::Value(value) => dict.set_item(name, value)?,
+            day_21_1::Statement::Operation(op) => {
+                dict.set_item(name, (op.key1, op.operand, op.key2))?
+            }
+        }
+    }
+    Ok(dict)
+}
+
+#[pyfunction]
+fn day21_solve(text: &str) -> (i64, i64) {
+    let monkeys = day_21_1::parse_monkeys(text.lines());
+    (
+        day_21_1::get_value(&monkeys, "root"),
+        day_21_1::humn_value(&monkeys),
+    )
+}
+
+#[pymodule]
+fn aoc_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(day01_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day01_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day02_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day02_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day03_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day03_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day04_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day04_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day05_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day05_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day06_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day07_directory_sizes, m)?)?;
+    m.add_function(wrap_pyfunction!(day07_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day08_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day08_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day09_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day09_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day10_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day10_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day11_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day11_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day12_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day12_distances, m)?)?;
+    m.add_function(wrap_pyfunction!(day12_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day13_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day13_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day14_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day14_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day15_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day15_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day16_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day16_permutations, m)?)?;
+    m.add_function(wrap_pyfunction!(day16_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day17_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day17_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day18_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day18_exterior_faces, m)?)?;
+    m.add_function(wrap_pyfunction!(day18_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day19_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day19_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day20_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day20_solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day21_parse, m)?)?;
+    m.add_function(wrap_pyfunction!(day21_solve, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,453 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+// Small xorshift generator, the anonymizer only needs reproducible noise.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn shuffle<T>(&mut self, values: &mut [T]) {
+        for i in (1..values.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            values.swap(i, j);
+        }
+    }
+}
+
+// Rewrite the identifying parts of a puzzle input so that it still parses. Days 10, 17 and 20 are
+// not handled.
+pub fn anonymize(day: u32, text: &str, rng: &mut Rng) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+
+    let lines = match day {
+        1 => map_numbers(&lines, |n| perturb(n, rng)),
+        // The letters get other meanings, consistently, and the rounds another order.
+        2 => {
+            let opponent = letter_permutation('A'..='C', rng);
+            let response = letter_permutation('X'..='Z', rng);
+            let mut lines = map_chars(&lines, |c| {
+                *opponent.get(&c).or(response.get(&c)).unwrap_or(&c)
+            });
+            rng.shuffle(&mut lines);
+            lines
+        }
+        3 => {
+            let lower = letter_permutation('a'..='z', rng);
+            let upper = letter_permutation('A'..='Z', rng);
+            map_chars(&lines, |c| *lower.get(&c).or(upper.get(&c)).unwrap_or(&c))
+        }
+        4 | 13 => {
+            let remap = monotone_remap(&lines, rng);
+            map_numbers(&lines, |n| remap[&n])
+        }
+        5 => anonymize_day05(&lines, rng),
+        6 => {
+            let letters = letter_permutation('a'..='z', rng);
+            map_chars(&lines, |c| *letters.get(&c).unwrap_or(&c))
+        }
+        7 => anonymize_day07(&lines, rng),
+        // Mirroring the grid keeps every line of sight and every path length.
+        8 | 12 => lines.iter().map(|l| l.chars().rev().collect()).collect(),
+        9 => map_chars(&lines, |c| match c {
+            'L' => 'R',
+            'R' => 'L',
+            c => c,
+        }),
+        11 => lines
+            .iter()
+            .map(|line| match line.strip_prefix("  Starting items: ") {
+                Some(_) => map_numbers(&[*line], |n| perturb(n, rng)).remove(0),
+                None => line.to_string(),
+            })
+            .collect(),
+        // Moving the rocks sideways keeps their shapes. They stay at the same height, below the
+        // sand source, and the paths go in another order.
+        14 => {
+            let offset = (1 + rng.below(5) as i64) * if rng.below(2) == 0 { -1 } else { 1 };
+            let mut is_x = false;
+            let mut lines = map_numbers(&lines, |n| {
+                is_x = !is_x;
+                if is_x {
+                    n + offset
+                } else {
+                    n
+                }
+            });
+            rng.shuffle(&mut lines);
+            lines
+        }
+        15 => anonymize_day15(&lines, rng),
+        16 => {
+            let mut names = NameGenerator::new(rng, 'A'..='Z', &["AA"]);
+            map_words(&lines, |w| {
+                w.len() == 2 && w.chars().all(|c| c.is_ascii_uppercase())
+            })
+            .into_iter()
+            .map(|words| words.rename(&mut names))
+            .collect()
+        }
+        18 => {
+            let offset = [rng.below(4), rng.below(4), rng.below(4)];
+            lines
+                .iter()
+                .map(|line| {
+                    line.split(',')
+                        .zip(offset)
+                        .map(|(n, o)| match n.parse::<i64>() {
+                            Ok(n) => (n + o as i64).to_string(),
+                            Err(_) => n.to_string(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect()
+        }
+        19 => anonymize_day19(&lines, rng),
+        21 => {
+            let mut names = NameGenerator::new(rng, 'a'..='z', &["root", "humn"]);
+            map_words(&lines, |w| {
+                w.len() == 4 && w.chars().all(|c| c.is_ascii_lowercase())
+            })
+            .into_iter()
+            .map(|words| words.rename(&mut names))
+            .collect()
+        }
+        day => return Err(format!("Day {} has no anonymizer", day)),
+    };
+
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Ok(out)
+}
+
+// Change a number by up to 5% while keeping its sign.
+fn perturb(n: i64, rng: &mut Rng) -> i64 {
+    let spread = (n.abs() / 20).max(1);
+    let delta = rng.below(2 * spread as u64 + 1) as i64 - spread;
+    let value = n + delta;
+
+    if n > 0 {
+        value.max(1)
+    } else if n < 0 {
+        value.min(-1)
+    } else {
+        0
+    }
+}
+
+fn letter_permutation(
+    letters: impl Iterator<Item = char> + Clone,
+    rng: &mut Rng,
+) -> HashMap<char, char> {
+    let mut shuffled = letters.clone().collect::<Vec<_>>();
+    rng.shuffle(&mut shuffled);
+    letters.zip(shuffled).collect()
+}
+
+// Relabel all the numbers of the input with new ones in the same order, so that comparisons
+// between them keep their outcome.
+fn monotone_remap(lines: &[&str], rng: &mut Rng) -> HashMap<i64, i64> {
+    let mut numbers = BTreeSet::new();
+    map_numbers(lines, |n| {
+        numbers.insert(n);
+        n
+    });
+
+    let mut next = numbers.iter().next().copied().unwrap_or(0).min(1);
+    numbers
+        .into_iter()
+        .map(|n| {
+            let value = next;
+            next += 1 + rng.below(2) as i64;
+            (n, value)
+        })
+        .collect()
+}
+
+fn map_chars(lines: &[&str], mut f: impl FnMut(char) -> char) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| line.chars().map(&mut f).collect())
+        .collect()
+}
+
+// Replace every integer of every line, a leading '-' is part of the number. Numbers too large for
+// an i64 are left as they are.
+fn map_numbers(lines: &[&str], mut f: impl FnMut(i64) -> i64) -> Vec<String> {
+    lines
+        .iter()
+        .map(|line| {
+            let chars = line.chars().collect::<Vec<_>>();
+            let mut out = String::new();
+            let mut i = 0;
+
+            while i < chars.len() {
+                let negative = chars[i] == '-'
+                    && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit())
+                    && (i == 0 || !chars[i - 1].is_ascii_alphanumeric());
+                if !chars[i].is_ascii_digit() && !negative {
+                    out.push(chars[i]);
+                    i += 1;
+                    continue;
+                }
+
+                let start = i;
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+                let number = chars[start..i].iter().collect::<String>();
+                match number.parse() {
+                    Ok(n) => out.push_str(&f(n).to_string()),
+                    Err(_) => out.push_str(&number),
+                }
+            }
+
+            out
+        })
+        .collect()
+}
+
+// A line split into alternating separators and words, where words are the tokens matching the
+// given predicate.
+struct Words(Vec<(String, bool)>);
+
+impl Words {
+    fn rename(self, names: &mut NameGenerator) -> String {
+        self.0
+            .into_iter()
+            .map(|(token, is_word)| if is_word { names.get(&token) } else { token })
+            .collect()
+    }
+}
+
+fn map_words(lines: &[&str], is_word: impl Fn(&str) -> bool) -> Vec<Words> {
+    lines
+        .iter()
+        .map(|line| {
+            let mut tokens = Vec::new();
+            let mut current = String::new();
+
+            for c in line.chars() {
+                if c.is_alphanumeric() || c == '.' || c == '_' {
+                    current.push(c);
+                    continue;
+                }
+                if !current.is_empty() {
+                    let word = std::mem::take(&mut current);
+                    let matches = is_word(&word);
+                    tokens.push((word, matches));
+                }
+                tokens.push((c.to_string(), false));
+            }
+            if !current.is_empty() {
+                let matches = is_word(&current);
+                tokens.push((current, matches));
+            }
+
+            Words(tokens)
+        })
+        .collect()
+}
+
+// Gives every name a new random one of the same length, the same name always getting the same
+// replacement.
+struct NameGenerator<'a> {
+    rng: &'a mut Rng,
+    alphabet: Vec<char>,
+    names: HashMap<String, String>,
+    used: HashSet<String>,
+}
+
+impl<'a> NameGenerator<'a> {
+    fn new(
+        rng: &'a mut Rng,
+        alphabet: impl Iterator<Item = char>,
+        keep: &[&str],
+    ) -> NameGenerator<'a> {
+        let names: HashMap<String, String> = keep
+            .iter()
+            .map(|name| (name.to_string(), name.to_string()))
+            .collect();
+        let used = names.keys().cloned().collect();
+
+        NameGenerator {
+            rng,
+            alphabet: alphabet.collect(),
+            names,
+            used,
+        }
+    }
+
+    fn get(&mut self, name: &str) -> String {
+        if let Some(new_name) = self.names.get(name) {
+            return new_name.clone();
+        }
+
+        let new_name = loop {
+            let candidate = name
+                .chars()
+                .map(|c| {
+                    if c.is_alphanumeric() {
+                        self.alphabet[self.rng.below(self.alphabet.len() as u64) as usize]
+                    } else {
+                        c
+                    }
+                })
+                .collect::<String>();
+            if self.used.insert(candidate.clone()) {
+                break candidate;
+            }
+        };
+
+        self.names.insert(name.to_string(), new_name.clone());
+        new_name
+    }
+}
+
+// Only the crate letters of the drawing change, the moves stay valid as is.
+fn anonymize_day05(lines: &[&str], rng: &mut Rng) -> Vec<String> {
+    let letters = letter_permutation('A'..='Z', rng);
+    let mut in_drawing = true;
+
+    lines
+        .iter()
+        .map(|line| {
+            if line.trim().is_empty() {
+                in_drawing = false;
+            }
+            if in_drawing && line.contains('[') {
+                line.chars()
+                    .map(|c| *letters.get(&c).unwrap_or(&c))
+                    .collect()
+            } else {
+                line.to_string()
+            }
+        })
+        .collect()
+}
+
+fn anonymize_day07(lines: &[&str], rng: &mut Rng) -> Vec<String> {
+    let mut sizes = Vec::new();
+    let mut renamed = Vec::new();
+    {
+        let mut names = NameGenerator::new(rng, 'a'..='z', &["/", ".."]);
+
+        for line in lines {
+            let parts = line.split(' ').collect::<Vec<_>>();
+            let line = match parts.as_slice() {
+                ["$", "cd", name] => format!("$ cd {}", names.get(name)),
+                ["dir", name] => format!("dir {}", names.get(name)),
+                [size, name] if size.parse::<i64>().is_ok() => {
+                    sizes.push(renamed.len());
+                    format!("{} {}", size, names.get(name))
+                }
+                _ => line.to_string(),
+            };
+            renamed.push(line);
+        }
+    }
+
+    for index in sizes {
+        let (size, name) = renamed[index].split_once(' ').unwrap();
+        renamed[index] = format!("{} {}", perturb(size.parse().unwrap(), rng), name);
+    }
+
+    renamed
+}
+
+// Part 1 looks at row 2000000 and part 2 from 0 to 4000000 on both axes. Mirroring around the
+// middle keeps both, and every distance between sensors and beacons.
+fn anonymize_day15(lines: &[&str], rng: &mut Rng) -> Vec<String> {
+    let mirrors = [(true, false), (false, true), (true, true)];
+    let (mirror_x, mirror_y) = mirrors[rng.below(3) as usize];
+    let mut is_x = false;
+
+    map_numbers(lines, |n| {
+        is_x = !is_x;
+        if (is_x && mirror_x) || (!is_x && mirror_y) {
+            4000000 - n
+        } else {
+            n
+        }
+    })
+}
+
+// The blueprints go in another order, numbered again from 1.
+fn anonymize_day19(lines: &[&str], rng: &mut Rng) -> Vec<String> {
+    let mut blueprints = lines
+        .iter()
+        .filter_map(|line| line.strip_prefix("Blueprint "))
+        .filter_map(|line| line.split_once(": "))
+        .map(|(_, robots)| robots)
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut blueprints);
+
+    blueprints
+        .iter()
+        .enumerate()
+        .map(|(i, robots)| format!("Blueprint {}: {}", i + 1, robots))
+        .collect()
+}
+
+#[test]
+fn test_map_numbers() {
+    let lines = ["x=-3, y=12", "99999999999999999999 -5"];
+    let mapped = map_numbers(&lines, |n| n + 1);
+    assert_eq!(mapped, ["x=-2, y=13", "99999999999999999999 -4"]);
+}
+
+#[test]
+fn test_monotone_remap() {
+    let mut rng = Rng::new(42);
+    let lines = ["2-4,6-8", "5-7,7-9"];
+    let remap = monotone_remap(&lines, &mut rng);
+    let mut numbers = remap.keys().copied().collect::<Vec<_>>();
+    numbers.sort();
+
+    for pair in numbers.windows(2) {
+        assert!(remap[&pair[0]] < remap[&pair[1]]);
+    }
+}
+
+#[test]
+fn test_anonymize_day15() {
+    let text = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n";
+    for seed in 0..10 {
+        let anonymized = anonymize(15, text, &mut Rng::new(seed)).unwrap();
+        assert!([
+            "Sensor at x=3999998, y=18: closest beacon is at x=4000002, y=15\n",
+            "Sensor at x=2, y=3999982: closest beacon is at x=-2, y=3999985\n",
+            "Sensor at x=3999998, y=3999982: closest beacon is at x=4000002, y=3999985\n",
+        ]
+        .contains(&anonymized.as_str()));
+    }
+}
+
+#[test]
+fn test_anonymize_day19() {
+    let text = "Blueprint 1: a\nBlueprint 2: b\nBlueprint 3: c\n";
+    let anonymized = anonymize(19, text, &mut Rng::new(7)).unwrap();
+    let mut robots = Vec::new();
+
+    for (i, line) in anonymized.lines().enumerate() {
+        let (id, rest) = line.split_once(": ").unwrap();
+        assert_eq!(id, format!("Blueprint {}", i + 1));
+        robots.push(rest);
+    }
+    robots.sort();
+    assert_eq!(robots, ["a", "b", "c"]);
+}
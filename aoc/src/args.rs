@@ -0,0 +1,55 @@
+use std::error::Error;
+use std::str::FromStr;
+
+// The solvers' own command line options, taken out of the arguments before the input source.
+
+pub fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    match args.iter().position(|arg| arg == name) {
+        Some(i) => {
+            args.remove(i);
+            true
+        }
+        None => false,
+    }
+}
+
+// An option followed by its value, e.g. `--top 3`.
+pub fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let Some(i) = args.iter().position(|arg| arg == name) else {
+        return Ok(None);
+    };
+    if i + 1 >= args.len() {
+        return Err(format!("Missing value after {}", name).into());
+    }
+
+    let value = args.remove(i + 1);
+    args.remove(i);
+    Ok(Some(value))
+}
+
+pub fn take_parsed<T>(args: &mut Vec<String>, name: &str) -> Result<Option<T>, Box<dyn Error>>
+where
+    T: FromStr,
+    T::Err: Error + 'static,
+{
+    match take_option(args, name)? {
+        Some(value) => {
+            let parsed = value
+                .parse()
+                .map_err(|e| format!("Invalid value {} for {}: {}", value, name, e))?;
+            Ok(Some(parsed))
+        }
+        None => Ok(None),
+    }
+}
+
+#[test]
+fn test_take_option() {
+    let mut args = vec!["--top".to_string(), "5".to_string(), "input".to_string()];
+    assert_eq!(take_parsed::<usize>(&mut args, "--top").unwrap(), Some(5));
+    assert_eq!(take_option(&mut args, "--csv").unwrap(), None);
+    assert_eq!(args, ["input"]);
+
+    let mut args = vec!["--top".to_string()];
+    assert!(take_option(&mut args, "--top").is_err());
+}
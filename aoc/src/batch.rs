@@ -0,0 +1,205 @@
+use crate::runner::{self, Run};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// Day 16 stores the visited valves as bits of a u64 indexed by valve.
+const DAY16_MAX_VALVES: usize = 64;
+// Day 17 keeps the tower in a fixed 131072-cell map, 7 cells wide.
+const DAY17_MAP_ROWS: usize = 131072 / 7;
+// The five rocks are 1, 3, 3, 4 and 2 rows high.
+const DAY17_ROWS_PER_5_ROCKS: usize = 1 + 3 + 3 + 4 + 2;
+// A rock appears 3 rows above the tower and is pushed before each step down, so it takes at
+// least 4 jets.
+const DAY17_MIN_JETS_PER_ROCK: usize = 4;
+// The tower repeats itself within about two passes over the jets.
+const DAY17_JET_PASSES: usize = 2;
+// Runs slower than this many times the median of the other inputs are flagged.
+const SLOW_FACTOR: f64 = 3.0;
+
+struct Row {
+    file: String,
+    bytes: usize,
+    lines: usize,
+    warnings: Vec<String>,
+    runs: Vec<Run>,
+}
+
+// Known limits of the solvers that an input can exceed before they even run.
+pub fn input_warnings(day: u32, text: &str) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    match day {
+        16 => {
+            let valves = text.lines().filter(|l| l.starts_with("Valve ")).count();
+            if valves > DAY16_MAX_VALVES {
+                warnings.push(format!(
+                    "{} valves do not fit the {}-valve bitset",
+                    valves, DAY16_MAX_VALVES
+                ));
+            }
+        }
+        17 => {
+            // At most one rock per 4 jets, each adding at most 13 / 5 rows on average, until
+            // the cycle is found.
+            let jets = text.trim().len();
+            let estimated_rows =
+                jets * DAY17_JET_PASSES * DAY17_ROWS_PER_5_ROCKS / (DAY17_MIN_JETS_PER_ROCK * 5);
+            if estimated_rows > DAY17_MAP_ROWS {
+                warnings.push(format!(
+                    "{} jets may need ~{} rows, the map holds {}",
+                    jets, estimated_rows, DAY17_MAP_ROWS
+                ));
+            }
+        }
+        _ => (),
+    }
+
+    warnings
+}
+
+fn input_files(dir: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = fs::read_dir(dir)
+        .map_err(|e| format!("Cannot read {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file())
+        .collect::<Vec<_>>();
+    files.sort();
+
+    if files.is_empty() {
+        return Err(format!("No input file in {}", dir.display()).into());
+    }
+
+    Ok(files)
+}
+
+fn truncate(text: &str, length: usize) -> String {
+    if text.chars().count() <= length {
+        return text.to_string();
+    }
+    let mut text = text.chars().take(length - 3).collect::<String>();
+    text.push_str("...");
+    text
+}
+
+fn median(mut durations: Vec<Duration>) -> Option<Duration> {
+    durations.sort();
+    durations.get(durations.len() / 2).copied()
+}
+
+fn print_table(table: &[Vec<String>]) {
+    let columns = table.iter().map(|row| row.len()).max().unwrap_or(0);
+    let widths = (0..columns)
+        .map(|c| {
+            table
+                .iter()
+                .map(|row| row.get(c).map_or(0, |cell| cell.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+
+    for row in table {
+        let line = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    }
+}
+
+// Solve every input of the directory with every part of the day, and print the answers side by
+// side along with what stands out: crashes, slow runs and known solver limits.
+pub fn batch(day: u32, dir: &Path) -> Result<(), Box<dyn Error>> {
+    let solvers = runner::solvers(day)?;
+    let mut rows = Vec::new();
+
+    for file in input_files(dir)? {
+        let text = fs::read_to_string(&file).unwrap_or_default();
+        let runs = solvers
+            .iter()
+            .map(|solver| solver.run(&file))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.push(Row {
+            file: file.file_name().unwrap().to_string_lossy().to_string(),
+            bytes: text.len(),
+            lines: text.lines().count(),
+            warnings: input_warnings(day, &text),
+            runs,
+        });
+    }
+
+    let medians = (0..solvers.len())
+        .map(|part| median(rows.iter().map(|r| r.runs[part].elapsed).collect()))
+        .collect::<Vec<_>>();
+
+    let mut header = vec![
+        "input".to_string(),
+        "bytes".to_string(),
+        "lines".to_string(),
+    ];
+    for solver in solvers.iter() {
+        header.push(format!("{} answer", solver.part()));
+        header.push("time".to_string());
+    }
+    header.push("flags".to_string());
+
+    let mut table = vec![header];
+    for row in rows.iter() {
+        let mut cells = vec![
+            row.file.clone(),
+            row.bytes.to_string(),
+            row.lines.to_string(),
+        ];
+        let mut flags = row.warnings.clone();
+
+        for (part, run) in row.runs.iter().enumerate() {
+            let part_name = solvers[part].part();
+            let answer = if run.success {
+                run.answer().to_string()
+            } else {
+                run.describe()
+            };
+            cells.push(truncate(&answer, 40));
+            cells.push(format!("{:.1?}", run.elapsed));
+
+            if !run.success {
+                flags.push(format!("{} crashed", part_name));
+            }
+            let slow = medians[part]
+                .map(|median| run.elapsed.as_secs_f64() > median.as_secs_f64() * SLOW_FACTOR)
+                .unwrap_or(false);
+            if rows.len() > 2 && slow {
+                flags.push(format!("{} slow", part_name));
+            }
+        }
+
+        cells.push(flags.join("; "));
+        table.push(cells);
+    }
+
+    print_table(&table);
+
+    Ok(())
+}
+
+#[test]
+fn test_input_warnings() {
+    let valves = |count: usize| {
+        (0..count)
+            .map(|i| format!("Valve V{} has flow rate=0; tunnels lead to valves AA\n", i))
+            .collect::<String>()
+    };
+    assert!(input_warnings(16, &valves(DAY16_MAX_VALVES)).is_empty());
+    assert_eq!(input_warnings(16, &valves(DAY16_MAX_VALVES + 1)).len(), 1);
+
+    assert!(input_warnings(17, &">".repeat(10000)).is_empty());
+    assert_eq!(input_warnings(17, &"<".repeat(30000)).len(), 1);
+    assert!(input_warnings(17, &">".repeat(14403)).is_empty());
+    assert_eq!(input_warnings(17, &">".repeat(14404)).len(), 1);
+    assert!(input_warnings(16, &"<".repeat(30000)).is_empty());
+}
@@ -0,0 +1,157 @@
+use serde_json::json;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+// Path of the asciinema recording, e.g. `AOC_CAST=sand.cast cargo run < input`.
+pub const CAST_ENV: &str = "AOC_CAST";
+// Only keep one frame out of this many, for simulations producing very large recordings.
+pub const CAST_EVERY_ENV: &str = "AOC_CAST_EVERY";
+
+// Records frames as an asciinema v2 `.cast` file. The header needs the terminal size, which is
+// only known once every frame has been seen, so the events are written to a temporary file first
+// and the cast is assembled when the recorder is dropped.
+pub struct Recorder {
+    output: Option<(PathBuf, PathBuf, BufWriter<File>)>,
+    start: Instant,
+    timestamp: u64,
+    width: usize,
+    height: usize,
+    every: u64,
+    frames: u64,
+}
+
+impl Recorder {
+    pub fn from_env() -> Recorder {
+        let every = env::var(CAST_EVERY_ENV)
+            .ok()
+            .and_then(|every| every.parse::<u64>().ok())
+            .unwrap_or(1);
+        Recorder::new(env::var(CAST_ENV).ok().map(PathBuf::from), every)
+    }
+
+    // Records to the path if there is one, keeping one frame out of `every`.
+    pub fn new(path: Option<PathBuf>, every: u64) -> Recorder {
+        let output = path.map(|path| {
+            let events_path = path.with_extension("cast.events");
+            let file = File::create(&events_path).unwrap_or_else(|e| {
+                panic!("Cannot create {}: {}", events_path.display(), e);
+            });
+            (path, events_path, BufWriter::new(file))
+        });
+
+        Recorder {
+            output,
+            start: Instant::now(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+            width: 1,
+            height: 1,
+            every: every.max(1),
+            frames: 0,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.output.is_some()
+    }
+
+    // Each frame replaces the whole screen.
+    pub fn frame(&mut self, screen: &str) {
+        let Some((_, _, events)) = self.output.as_mut() else {
+            return;
+        };
+
+        let index = self.frames;
+        self.frames += 1;
+        if !index.is_multiple_of(self.every) {
+            return;
+        }
+
+        let screen = screen.trim_end_matches('\n');
+        self.width = self
+            .width
+            .max(screen.lines().map(|l| l.chars().count()).max().unwrap_or(0));
+        self.height = self.height.max(screen.lines().count() + 1);
+
+        let data = format!("\x1b[2J\x1b[H{}\r\n", screen.replace('\n', "\r\n"));
+        let event = json!([self.start.elapsed().as_secs_f64(), "o", data]);
+        serde_json::to_writer(&mut *events, &event).unwrap();
+        events.write_all(b"\n").unwrap();
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        let Some((path, events_path, mut events)) = self.output.take() else {
+            return Ok(());
+        };
+        events.flush()?;
+        drop(events);
+
+        let header = json!({
+            "version": 2,
+            "width": self.width,
+            "height": self.height,
+            "timestamp": self.timestamp,
+            "env": { "TERM": "xterm-256color", "SHELL": "/bin/sh" },
+        });
+
+        let mut cast = BufWriter::new(File::create(&path)?);
+        serde_json::to_writer(&mut cast, &header)?;
+        cast.write_all(b"\n")?;
+        for line in BufReader::new(File::open(&events_path)?).lines() {
+            cast.write_all(line?.as_bytes())?;
+            cast.write_all(b"\n")?;
+        }
+        cast.flush()?;
+
+        fs::remove_file(events_path)
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Err(e) = self.finish() {
+            eprintln!("Cannot write the cast file: {}", e);
+        }
+    }
+}
+
+#[test]
+fn test_recorder() {
+    let path = env::temp_dir().join(format!("aoc-test-{}.cast", std::process::id()));
+    let mut recorder = Recorder::new(Some(path.clone()), 2);
+    recorder.frame("ab\ncd\n");
+    recorder.frame("skipped");
+    recorder.frame("abcde");
+    drop(recorder);
+
+    let text = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let lines = text
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0]["version"], 2);
+    assert_eq!(
+        (&lines[0]["width"], &lines[0]["height"]),
+        (&json!(5), &json!(3))
+    );
+
+    let frames = lines[1..]
+        .iter()
+        .map(|frame| (frame[1].as_str().unwrap(), frame[2].as_str().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        frames,
+        [
+            ("o", "\x1b[2J\x1b[Hab\r\ncd\r\n"),
+            ("o", "\x1b[2J\x1b[Habcde\r\n")
+        ]
+    );
+    assert!(lines[1][0].as_f64().unwrap() <= lines[2][0].as_f64().unwrap());
+}
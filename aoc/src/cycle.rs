@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Finds when a simulation starts repeating itself. States are pushed once per step, and the
+// first state seen twice gives the cycle. The state must capture everything that decides the
+// following steps, otherwise the cycle found is only a coincidence.
+pub struct CycleDetector<S> {
+    seen: HashMap<S, usize>,
+    steps: usize,
+}
+
+// Steps `start..start + period` repeat forever, so the state at `start + period` is the state at
+// `start` again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl<S: Hash + Eq> CycleDetector<S> {
+    pub fn new() -> CycleDetector<S> {
+        CycleDetector {
+            seen: HashMap::new(),
+            steps: 0,
+        }
+    }
+
+    // The state after `steps` steps, the first push being step 0.
+    pub fn push(&mut self, state: S) -> Option<Cycle> {
+        let step = self.steps;
+        self.steps += 1;
+
+        self.seen.insert(state, step).map(|start| Cycle {
+            start,
+            period: step - start,
+        })
+    }
+}
+
+impl<S: Hash + Eq> Default for CycleDetector<S> {
+    fn default() -> CycleDetector<S> {
+        CycleDetector::new()
+    }
+}
+
+// The metrics passed to a cycle are indexed by step, and must go at least up to the step where the
+// cycle was found.
+impl Cycle {
+    // How much the metric grows over one period.
+    pub fn delta(&self, metric: &[i64]) -> i64 {
+        metric[self.start + self.period] - metric[self.start]
+    }
+
+    // The metric at any step, however far.
+    pub fn extrapolate(&self, metric: &[i64], step: usize) -> i64 {
+        if step < self.start {
+            return metric[step];
+        }
+
+        let periods = (step - self.start) / self.period;
+        let offset = (step - self.start) % self.period;
+        metric[self.start + offset] + periods as i64 * self.delta(metric)
+    }
+}
+
+#[test]
+fn test_extrapolate() {
+    // A prefix of 2 steps, then a period of 3 steps adding 10 each time.
+    let states = [7, 8, 1, 2, 3, 1];
+    let metric = [0, 1, 2, 5, 6, 12];
+
+    let mut detector = CycleDetector::new();
+    let cycle = states.iter().find_map(|s| detector.push(*s)).unwrap();
+    assert_eq!(
+        cycle,
+        Cycle {
+            start: 2,
+            period: 3
+        }
+    );
+    assert_eq!(cycle.delta(&metric), 10);
+
+    for step in 0..metric.len() {
+        assert_eq!(cycle.extrapolate(&metric, step), metric[step]);
+    }
+    assert_eq!(cycle.extrapolate(&metric, 9), 25);
+    assert_eq!(cycle.extrapolate(&metric, 3002), 10002);
+}
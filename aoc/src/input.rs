@@ -0,0 +1,316 @@
+use memmap2::Mmap;
+use std::env;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::str;
+
+// The file names the puzzle inputs and the examples go by, looked up in this order in the
+// directory the solver is run from.
+pub const INPUT_NAMES: [&str; 3] = ["input", "input.txt", "input1.txt"];
+pub const EXAMPLE_NAMES: [&str; 3] = ["test", "test.txt", "test1"];
+
+const SOURCE_USAGE: &str = "pass an input file, `-` for stdin or --example";
+
+// Where to read the puzzle input from, as given on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+    Example,
+    // Nothing given: stdin when it is redirected from a file, otherwise the day's input file. A
+    // pipe must be given as `-`.
+    Default,
+}
+
+// The whole puzzle input, loaded once. Files are memory-mapped and stdin is mapped too when it
+// is redirected from a file, so solvers borrow their lines instead of allocating one `String`
+// per line.
+pub struct Input {
+    data: Data,
+}
+
+enum Data {
+    Mapped(Mmap),
+    Buffered(Vec<u8>),
+}
+
+fn invalid_utf8(e: str::Utf8Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+// Empty files can't be mapped.
+fn map_file(file: &File) -> io::Result<Option<Data>> {
+    let metadata = file.metadata()?;
+    if !metadata.is_file() || metadata.len() == 0 {
+        return Ok(None);
+    }
+
+    // Safety: the input files are not modified while a solver runs.
+    let mmap = unsafe { Mmap::map(file)? };
+    Ok(Some(Data::Mapped(mmap)))
+}
+
+#[cfg(unix)]
+fn map_stdin() -> io::Result<Option<Data>> {
+    use std::os::fd::AsFd;
+
+    let fd = io::stdin().as_fd().try_clone_to_owned()?;
+    map_file(&File::from(fd))
+}
+
+#[cfg(not(unix))]
+fn map_stdin() -> io::Result<Option<Data>> {
+    Ok(None)
+}
+
+// Redirected from a file with something in it. Solvers run from scripts often get an empty
+// /dev/null as stdin, which falls back to the day's input file. A pipe can't be told apart from
+// one nothing is ever written to, so rather than solving another input, piped input needs an
+// explicit `-`.
+#[cfg(unix)]
+fn stdin_is_redirected() -> Result<bool, Box<dyn Error>> {
+    use std::os::fd::AsFd;
+    use std::os::unix::fs::FileTypeExt;
+
+    if io::stdin().is_terminal() {
+        return Ok(false);
+    }
+
+    let metadata = io::stdin()
+        .as_fd()
+        .try_clone_to_owned()
+        .and_then(|fd| File::from(fd).metadata());
+    let Ok(metadata) = metadata else {
+        return Ok(false);
+    };
+    if metadata.file_type().is_fifo() || metadata.file_type().is_socket() {
+        return Err(format!("stdin is a pipe, {}", SOURCE_USAGE).into());
+    }
+    Ok(metadata.is_file() && metadata.len() > 0)
+}
+
+#[cfg(not(unix))]
+fn stdin_is_redirected() -> Result<bool, Box<dyn Error>> {
+    Ok(!io::stdin().is_terminal())
+}
+
+fn find_file(names: &[&str]) -> Result<PathBuf, Box<dyn Error>> {
+    let dir = env::current_dir()?;
+    names
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+        .ok_or_else(|| {
+            format!(
+                "No input found: none of {} exists in {}, {}",
+                names.join(", "),
+                dir.display(),
+                SOURCE_USAGE
+            )
+            .into()
+        })
+}
+
+impl Source {
+    // Takes the input source out of the command line arguments, leaving the other arguments to
+    // the solver. The first positional argument is the input, so solver options taking a value
+    // must be taken out before.
+    pub fn from_args(args: &mut Vec<String>) -> Result<Source, Box<dyn Error>> {
+        let mut source = Source::Default;
+
+        let mut i = 0;
+        while i < args.len() {
+            let arg = args[i].as_str();
+            let given = match arg {
+                "--example" => Source::Example,
+                "-" => Source::Stdin,
+                _ if arg.starts_with('-') => {
+                    i += 1;
+                    continue;
+                }
+                _ if source == Source::Default => Source::Path(PathBuf::from(arg)),
+                _ => {
+                    i += 1;
+                    continue;
+                }
+            };
+
+            if source != Source::Default {
+                return Err(format!("More than one input given, {}", SOURCE_USAGE).into());
+            }
+            source = given;
+            args.remove(i);
+        }
+
+        Ok(source)
+    }
+
+    // The source given in what's left of the command line, which must be nothing else.
+    pub fn from_remaining_args(mut args: Vec<String>) -> Result<Source, Box<dyn Error>> {
+        let source = Source::from_args(&mut args)?;
+        if let Some(arg) = args.first() {
+            return Err(format!("Unexpected argument {}, {}", arg, SOURCE_USAGE).into());
+        }
+
+        Ok(source)
+    }
+
+    pub fn resolve(self) -> Result<Source, Box<dyn Error>> {
+        match self {
+            Source::Default if stdin_is_redirected()? => Ok(Source::Stdin),
+            Source::Default => Ok(Source::Path(find_file(&INPUT_NAMES)?)),
+            Source::Example => Ok(Source::Path(find_file(&EXAMPLE_NAMES)?)),
+            source => Ok(source),
+        }
+    }
+
+    // Reads the input as it goes instead of loading it, for inputs too large to fit in memory.
+    pub fn reader(self) -> Result<Box<dyn BufRead>, Box<dyn Error>> {
+        match self.resolve()? {
+            Source::Path(path) => {
+                let file = File::open(&path)
+                    .map_err(|e| format!("Cannot open {}: {}", path.display(), e))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            _ => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+impl Input {
+    pub fn stdin() -> io::Result<Input> {
+        let data = match map_stdin()? {
+            Some(data) => data,
+            None => {
+                let mut buffer = Vec::new();
+                io::stdin().lock().read_to_end(&mut buffer)?;
+                Data::Buffered(buffer)
+            }
+        };
+
+        Input::new(data)
+    }
+
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Input> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| {
+            io::Error::new(e.kind(), format!("Cannot open {}: {}", path.display(), e))
+        })?;
+        let data = match map_file(&file)? {
+            Some(data) => data,
+            None => Data::Buffered(Vec::new()),
+        };
+
+        Input::new(data)
+    }
+
+    pub fn from_source(source: Source) -> Result<Input, Box<dyn Error>> {
+        match source.resolve()? {
+            Source::Path(path) => Ok(Input::open(path).map_err(|e| e.to_string())?),
+            _ => {
+                if io::stdin().is_terminal() {
+                    eprintln!("Reading the input from stdin...");
+                }
+                Ok(Input::stdin()?)
+            }
+        }
+    }
+
+    // The input given on the command line, for solvers without options of their own.
+    pub fn from_args() -> Result<Input, Box<dyn Error>> {
+        Input::from_remaining_args(env::args().skip(1).collect())
+    }
+
+    // The input given in what's left of the command line once the solver took its options.
+    pub fn from_remaining_args(args: Vec<String>) -> Result<Input, Box<dyn Error>> {
+        Input::from_source(Source::from_remaining_args(args)?)
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> io::Result<Input> {
+        Input::new(Data::Buffered(bytes))
+    }
+
+    // Inputs are checked once here, so that `text` can't fail afterwards.
+    fn new(data: Data) -> io::Result<Input> {
+        let input = Input { data };
+        str::from_utf8(input.bytes()).map_err(invalid_utf8)?;
+        Ok(input)
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        match &self.data {
+            Data::Mapped(mmap) => mmap,
+            Data::Buffered(buffer) => buffer,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        str::from_utf8(self.bytes()).unwrap()
+    }
+
+    // Lines without their "\n" or "\r\n" ending. A missing newline at the end of the input
+    // doesn't make a difference.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text().lines()
+    }
+
+    pub fn byte_lines(&self) -> impl Iterator<Item = &[u8]> {
+        let bytes = self.bytes();
+        let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+
+        bytes
+            .split(|b| *b == b'\n')
+            .filter(move |_| !bytes.is_empty())
+            .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+    }
+
+    // Groups of lines separated by blank lines.
+    pub fn groups(&self) -> impl Iterator<Item = Vec<&str>> {
+        let mut lines = self.lines().peekable();
+
+        std::iter::from_fn(move || {
+            while lines.peek()?.trim().is_empty() {
+                lines.next();
+            }
+
+            let mut group = Vec::new();
+            while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+                group.push(line);
+            }
+            Some(group)
+        })
+    }
+}
+
+#[test]
+fn test_source_from_args() {
+    let mut args = vec!["--csv".to_string(), "input2".to_string(), "3".to_string()];
+    let source = Source::from_args(&mut args).unwrap();
+    assert_eq!(source, Source::Path(PathBuf::from("input2")));
+    assert_eq!(args, ["--csv", "3"]);
+
+    let mut args = vec!["--example".to_string()];
+    assert_eq!(Source::from_args(&mut args).unwrap(), Source::Example);
+    assert!(args.is_empty());
+
+    let mut args = vec!["-".to_string(), "--example".to_string()];
+    assert!(Source::from_args(&mut args).is_err());
+}
+
+#[test]
+fn test_lines() {
+    for text in ["a\nbb\n\nc\n", "a\r\nbb\r\n\r\nc\r\n", "a\nbb\n\nc"] {
+        let input = Input::from_bytes(text.as_bytes().to_vec()).unwrap();
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["a", "bb", "", "c"]);
+        assert_eq!(
+            input.byte_lines().collect::<Vec<_>>(),
+            [&b"a"[..], b"bb", b"", b"c"]
+        );
+        assert_eq!(
+            input.groups().collect::<Vec<_>>(),
+            [vec!["a", "bb"], vec!["c"]]
+        );
+    }
+}
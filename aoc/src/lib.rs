@@ -0,0 +1,9 @@
+pub mod anonymize;
+pub mod args;
+pub mod batch;
+pub mod cast;
+pub mod cycle;
+pub mod input;
+pub mod replay;
+pub mod runner;
+pub mod trace;
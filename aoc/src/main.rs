@@ -0,0 +1,164 @@
+use aoc::anonymize::{anonymize, Rng};
+use aoc::runner::{self, Run};
+use aoc::{batch, replay, trace};
+use std::env;
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "Usage:
+  aoc replay <trace.jsonl> [step]
+  aoc anonymize <day> <file> [--seed <n>] [--output <file>] [--no-check]
+  aoc batch <day> <dir>";
+
+fn replay(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let path = args.first().ok_or(USAGE)?;
+    let events = trace::read_trace(path)?;
+    let last = events.last().ok_or("Empty trace")?;
+    let step = match args.get(1) {
+        Some(step) => step.parse::<u64>()?,
+        None => last.step,
+    };
+
+    print!("{}", replay::render(&events, step)?);
+
+    Ok(())
+}
+
+// Both runs behave the same when they both succeed, or both panic at the same place.
+fn same_behaviour(original: &Run, anonymized: &Run) -> bool {
+    original.success == anonymized.success
+        && original.panic_location() == anonymized.panic_location()
+}
+
+// A file only this process can have created, removed when dropped.
+struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    fn create(day: u32, contents: &str) -> Result<TempFile, Box<dyn Error>> {
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos();
+        for attempt in 0..100 {
+            let path = env::temp_dir().join(format!(
+                "aoc-anonymized-day-{:02}-{}-{}-{}",
+                day,
+                process::id(),
+                nanos,
+                attempt
+            ));
+            match options.open(&path) {
+                Ok(mut file) => {
+                    let temp = TempFile { path };
+                    file.write_all(contents.as_bytes())?;
+                    return Ok(temp);
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Err("Can't create a temporary file".into())
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn anonymize_input(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let day = args.first().ok_or(USAGE)?.parse::<u32>()?;
+    let path = PathBuf::from(args.get(1).ok_or(USAGE)?);
+    let mut seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+    let mut output = None;
+    let mut check = true;
+
+    let mut options = args[2..].iter();
+    while let Some(option) = options.next() {
+        match option.as_str() {
+            "--seed" => seed = options.next().ok_or(USAGE)?.parse()?,
+            "--output" => output = Some(PathBuf::from(options.next().ok_or(USAGE)?)),
+            "--no-check" => check = false,
+            _ => return Err(USAGE.into()),
+        }
+    }
+
+    let text = fs::read_to_string(&path)?;
+    let anonymized = anonymize(day, &text, &mut Rng::new(seed))?;
+
+    let mut temp = None;
+    let anonymized_path = match output {
+        Some(output) => {
+            fs::write(&output, &anonymized)?;
+            output
+        }
+        None => {
+            print!("{}", anonymized);
+            if !check {
+                return Ok(());
+            }
+            temp.insert(TempFile::create(day, &anonymized)?)
+                .path
+                .clone()
+        }
+    };
+
+    if !check {
+        return Ok(());
+    }
+
+    // The report goes to stderr so that stdout can be redirected to a file.
+    let mut all_same = true;
+    for solver in runner::solvers(day)? {
+        let original = solver.run(&path)?;
+        let result = solver.run(&anonymized_path)?;
+        let same = same_behaviour(&original, &result);
+        all_same &= same;
+
+        eprintln!(
+            "{}: original {}, anonymized {} => {}",
+            solver.part(),
+            original.describe(),
+            result.describe(),
+            if same {
+                "same behaviour"
+            } else {
+                "DIFFERENT behaviour"
+            }
+        );
+    }
+
+    if !all_same {
+        return Err("The anonymized input behaves differently, try another --seed".into());
+    }
+
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|s| s.as_str()) {
+        Some("replay") => replay(&args[1..]),
+        Some("anonymize") => anonymize_input(&args[1..]),
+        Some("batch") => {
+            let day = args.get(1).ok_or(USAGE)?.parse::<u32>()?;
+            let dir = args.get(2).ok_or(USAGE)?;
+            batch::batch(day, Path::new(dir))
+        }
+        _ => Err(USAGE.into()),
+    }
+}
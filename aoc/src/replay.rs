@@ -0,0 +1,406 @@
+use crate::trace::Event;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+
+// Render the state of the simulation right after the given step. Simulators that only record
+// transitions (days 11, 14, 17 and 20) are rebuilt by folding the events up to that step.
+pub fn render(events: &[Event], step: u64) -> Result<String, Box<dyn Error>> {
+    let index = events
+        .iter()
+        .position(|e| e.step == step)
+        .ok_or(format!("No step {} in trace", step))?;
+    let event = &events[index];
+    let history = &events[..=index];
+
+    let mut out = format!("day {}, step {}, {}\n", event.day, event.step, event.name);
+    let frame = match event.day {
+        5 => render_day05(event)?,
+        9 => render_day09(event)?,
+        10 => render_day10(event)?,
+        11 => render_day11(history)?,
+        14 => render_day14(history)?,
+        17 => render_day17(history)?,
+        20 => render_day20(history)?,
+        day => return Err(format!("No renderer for day {}", day).into()),
+    };
+    out.push_str(&frame);
+
+    Ok(out)
+}
+
+// A missing list is empty, a list with anything but points in it is an error.
+fn points(event: &Event, field: &str) -> Result<Vec<(i64, i64)>, Box<dyn Error>> {
+    let points = match event.get(field).as_array() {
+        Some(points) => points,
+        None => return Ok(Vec::new()),
+    };
+
+    points
+        .iter()
+        .map(|p| point(p).ok_or_else(|| event.invalid(field)))
+        .collect()
+}
+
+fn point(value: &Value) -> Option<(i64, i64)> {
+    Some((value[0].as_i64()?, value[1].as_i64()?))
+}
+
+fn strings(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|values| {
+            values
+                .iter()
+                .map(|v| v.as_str().unwrap_or_default().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn numbers(value: &Value) -> Option<Vec<i64>> {
+    match value.as_array() {
+        Some(values) => values.iter().map(|v| v.as_i64()).collect(),
+        None => Some(Vec::new()),
+    }
+}
+
+// The drawings are recorded by day 5 itself, in the format of its input.
+fn render_day05(event: &Event) -> Result<String, Box<dyn Error>> {
+    let mut out = String::new();
+
+    if event.name == "move" {
+        out.push_str(&format!(
+            "{}: move {} from {} to {}\n",
+            event.get("crane").as_str().unwrap_or_default(),
+            event.i64("amount")?,
+            event.i64("from")?,
+            event.i64("to")?
+        ));
+    }
+    out.push_str(event.get("drawing").as_str().unwrap_or_default());
+
+    Ok(out)
+}
+
+fn render_day09(event: &Event) -> Result<String, Box<dyn Error>> {
+    let knots = points(event, "knots")?;
+    let mut out = format!(
+        "{} (visited: {})\n",
+        event.get("line").as_str().unwrap_or_default(),
+        event.i64("visited")?
+    );
+
+    // The start is always drawn.
+    let xs = knots.iter().map(|k| k.0);
+    let ys = knots.iter().map(|k| k.1);
+    let (min_x, max_x) = (xs.clone().fold(0, i64::min), xs.fold(0, i64::max));
+    let (min_y, max_y) = (ys.clone().fold(0, i64::min), ys.fold(0, i64::max));
+
+    for y in (min_y..=max_y).rev() {
+        for x in min_x..=max_x {
+            let c = match knots.iter().position(|k| *k == (x, y)) {
+                Some(0) => 'H',
+                Some(_) if knots.len() == 2 => 'T',
+                Some(n) => char::from_digit(n as u32, 36).unwrap_or('*'),
+                None if (x, y) == (0, 0) => 's',
+                None => '.',
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+fn render_day10(event: &Event) -> Result<String, Box<dyn Error>> {
+    let mut out = format!(
+        "cycle {}, x: {}, instruction: {}\n",
+        event.i64("cycle")?,
+        event.i64("x")?,
+        event.get("instruction").as_str().unwrap_or("-")
+    );
+
+    for row in strings(event.get("crt")) {
+        out.push_str(&row);
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+fn render_day11(history: &[Event]) -> Result<String, Box<dyn Error>> {
+    let mut monkeys: Vec<VecDeque<i64>> = Vec::new();
+    let mut inspections: Vec<i64> = Vec::new();
+    let mut out = String::new();
+
+    for event in history {
+        match event.name.as_str() {
+            "start" => {
+                monkeys = event
+                    .get("monkeys")
+                    .as_array()
+                    .ok_or_else(|| event.invalid("monkeys"))?
+                    .iter()
+                    .map(|items| numbers(items).map(VecDeque::from))
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| event.invalid("monkeys"))?;
+                inspections = vec![0; monkeys.len()];
+                out = "initial items\n".to_string();
+            }
+            "throw" => {
+                let monkey = event.i64("monkey")? as usize;
+                let to = event.i64("to")? as usize;
+                let worry = event.i64("worry")?;
+                if monkey >= monkeys.len() {
+                    return Err(event.invalid("monkey"));
+                }
+                if to >= monkeys.len() {
+                    return Err(event.invalid("to"));
+                }
+                monkeys[monkey].pop_front();
+                monkeys[to].push_back(worry);
+                inspections[monkey] += 1;
+                out = format!(
+                    "round {}: monkey {} throws item {} as {} to monkey {}\n",
+                    event.i64("round")?,
+                    monkey,
+                    event.i64("item")?,
+                    worry,
+                    to
+                );
+            }
+            _ => (),
+        }
+    }
+
+    for (n, items) in monkeys.iter().enumerate() {
+        let items = items.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+        out.push_str(&format!(
+            "Monkey {} ({} inspections): {}\n",
+            n,
+            inspections[n],
+            items.join(", ")
+        ));
+    }
+
+    Ok(out)
+}
+
+fn render_day14(history: &[Event]) -> Result<String, Box<dyn Error>> {
+    let mut rocks: HashSet<(i64, i64)> = HashSet::new();
+    let mut sand: HashSet<(i64, i64)> = HashSet::new();
+    let mut floor: Option<i64> = None;
+    let mut path: Vec<(i64, i64)> = Vec::new();
+    let mut header = String::new();
+
+    for event in history {
+        match event.name.as_str() {
+            "start" => {
+                rocks = points(event, "rocks")?.into_iter().collect();
+                floor = event.get("floor").as_i64();
+            }
+            "grain" => {
+                path = points(event, "path")?;
+                header = format!("unit {}", event.i64("unit")?);
+                let rest = event.get("rest");
+                if rest.is_null() {
+                    header.push_str(" falls into the abyss");
+                } else {
+                    sand.insert(point(rest).ok_or_else(|| event.invalid("rest"))?);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let all = || rocks.iter().chain(sand.iter()).chain(path.iter());
+    let min_x = all().map(|p| p.0).min().unwrap_or(500).min(500);
+    let max_x = all().map(|p| p.0).max().unwrap_or(500).max(500);
+    let max_y = all()
+        .map(|p| p.1)
+        .max()
+        .unwrap_or(0)
+        .max(floor.unwrap_or(0));
+    let path: HashSet<&(i64, i64)> = path.iter().collect();
+
+    let mut out = format!("{}\n", header);
+    for y in 0..=max_y {
+        for x in min_x..=max_x {
+            let c = if Some(y) == floor || rocks.contains(&(x, y)) {
+                '#'
+            } else if sand.contains(&(x, y)) {
+                'o'
+            } else if path.contains(&(x, y)) {
+                '~'
+            } else if (x, y) == (500, 0) {
+                '+'
+            } else {
+                '.'
+            };
+            out.push(c);
+        }
+        out.push('\n');
+    }
+
+    Ok(out)
+}
+
+// Only the rows around the last dropped piece are drawn, the tower can be millions of rows high.
+fn render_day17(history: &[Event]) -> Result<String, Box<dyn Error>> {
+    const ROWS: i64 = 24;
+    let mut cells: HashMap<(i64, i64), char> = HashMap::new();
+    let mut last: Vec<(i64, i64)> = Vec::new();
+    let mut header = String::new();
+    let mut top = 0;
+
+    for event in history {
+        match event.name.as_str() {
+            "drop" => {
+                for cell in last.drain(..) {
+                    cells.insert(cell, '#');
+                }
+                last = points(event, "cells")?;
+                for cell in last.iter() {
+                    cells.insert(*cell, '@');
+                }
+                top = event.i64("height")?;
+                header = format!(
+                    "piece {} (shape {}, jet {}), height {}",
+                    event.i64("piece")?,
+                    event.i64("shape")?,
+                    event.i64("jet")?,
+                    top
+                );
+            }
+            "skip" => {
+                header = format!(
+                    "skipped {} cycles, {} rows",
+                    event.i64("cycles")?,
+                    event.i64("height")?
+                );
+            }
+            _ => (),
+        }
+    }
+
+    let mut out = format!("{}\n", header);
+    for y in (0.max(top - ROWS)..top).rev() {
+        out.push('|');
+        for x in 0..7 {
+            out.push(*cells.get(&(x, y)).unwrap_or(&'.'));
+        }
+        out.push_str(&format!("| {}\n", y));
+    }
+    if top <= ROWS {
+        out.push_str("+-------+\n");
+    }
+
+    Ok(out)
+}
+
+// Same adjacent swaps as the solver, so the replayed list matches it exactly.
+fn render_day20(history: &[Event]) -> Result<String, Box<dyn Error>> {
+    let mut list: Vec<(i64, i64)> = Vec::new();
+    let mut moved = None;
+    let mut header = String::new();
+
+    for event in history {
+        match event.name.as_str() {
+            "start" => {
+                list = numbers(event.get("values"))
+                    .ok_or_else(|| event.invalid("values"))?
+                    .into_iter()
+                    .enumerate()
+                    .map(|(id, value)| (id as i64, value))
+                    .collect();
+                header = "initial".to_string();
+            }
+            "mix" => {
+                let len = list.len() as i64;
+                if len == 0 {
+                    return Err(event.invalid("from"));
+                }
+                let from = event.i64("from")?;
+                let shift = event.i64("shift")?;
+                let sign = shift.signum();
+                for n in 0..shift.abs() {
+                    let i = (from + sign * n).rem_euclid(len) as usize;
+                    let j = (from + sign * (n + 1)).rem_euclid(len) as usize;
+                    list.swap(i, j);
+                }
+                moved = Some(event.i64("id")?);
+                header = format!(
+                    "round {}: move {} by {}",
+                    event.i64("round")?,
+                    event.i64("value")?,
+                    shift
+                );
+            }
+            _ => (),
+        }
+    }
+
+    let values = list
+        .iter()
+        .map(|(id, value)| {
+            if Some(*id) == moved {
+                format!("[{}]", value)
+            } else {
+                value.to_string()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(format!("{}\n{}\n", header, values.join(" ")))
+}
+
+#[test]
+fn test_render_day20() {
+    let events: Vec<Event> = [
+        r#"{"day":20,"step":0,"event":"start","values":[1,2,-3,3,-2,0,4]}"#,
+        r#"{"day":20,"step":1,"event":"mix","round":1,"id":0,"value":1,"from":0,"shift":1}"#,
+        r#"{"day":20,"step":2,"event":"mix","round":1,"id":1,"value":2,"from":0,"shift":2}"#,
+    ]
+    .iter()
+    .enumerate()
+    .map(|(n, line)| {
+        let fields: Value = serde_json::from_str(line).unwrap();
+        Event {
+            line: n + 1,
+            day: 20,
+            step: fields["step"].as_u64().unwrap(),
+            name: fields["event"].as_str().unwrap().to_string(),
+            fields,
+        }
+    })
+    .collect();
+
+    assert_eq!(
+        render(&events, 1).unwrap(),
+        "day 20, step 1, mix\nround 1: move 1 by 1\n2 [1] -3 3 -2 0 4\n"
+    );
+    assert_eq!(
+        render(&events, 2).unwrap(),
+        "day 20, step 2, mix\nround 1: move 2 by 2\n1 -3 [2] 3 -2 0 4\n"
+    );
+}
+
+#[test]
+fn test_render_invalid_field() {
+    let line = r#"{"day":20,"step":0,"event":"start","values":[1,"two"]}"#;
+    let fields: Value = serde_json::from_str(line).unwrap();
+    let events = [Event {
+        line: 3,
+        day: 20,
+        step: 0,
+        name: "start".to_string(),
+        fields,
+    }];
+
+    assert_eq!(
+        render(&events, 0).unwrap_err().to_string(),
+        "Line 3: invalid field values in start event"
+    );
+}
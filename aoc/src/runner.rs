@@ -0,0 +1,167 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+// The day crates live next to this one, either directly in `day-XX` or in one crate per part.
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+pub struct Solver {
+    pub dir: PathBuf,
+    pub name: String,
+    nightly: bool,
+}
+
+pub struct Run {
+    pub success: bool,
+    pub stdout: String,
+    pub stderr: String,
+    pub elapsed: Duration,
+}
+
+fn package_name(manifest: &Path) -> Result<String, Box<dyn Error>> {
+    let text = fs::read_to_string(manifest)?;
+    let name = text
+        .lines()
+        .find_map(|line| line.strip_prefix("name = "))
+        .ok_or(format!("No package name in {}", manifest.display()))?;
+
+    Ok(name.trim_matches('"').to_string())
+}
+
+fn solver(dir: PathBuf) -> Result<Solver, Box<dyn Error>> {
+    let name = package_name(&dir.join("Cargo.toml"))?;
+    let main = fs::read_to_string(dir.join("src/main.rs")).unwrap_or_default();
+
+    Ok(Solver {
+        dir,
+        name,
+        nightly: main.contains("#![feature"),
+    })
+}
+
+// All the crates solving a day, in part order.
+pub fn solvers(day: u32) -> Result<Vec<Solver>, Box<dyn Error>> {
+    let day_dir = repo_root().join(format!("day-{:02}", day));
+    if day_dir.join("Cargo.toml").exists() {
+        return Ok(vec![solver(day_dir)?]);
+    }
+
+    let mut dirs = fs::read_dir(&day_dir)
+        .map_err(|e| format!("Cannot read {}: {}", day_dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.join("Cargo.toml").exists())
+        .collect::<Vec<_>>();
+    dirs.sort();
+
+    if dirs.is_empty() {
+        return Err(format!("No crate found for day {}", day).into());
+    }
+
+    dirs.into_iter().map(solver).collect()
+}
+
+impl Solver {
+    pub fn part(&self) -> String {
+        self.dir.file_name().unwrap().to_string_lossy().to_string()
+    }
+
+    fn cargo(&self) -> Command {
+        let mut command = Command::new("cargo");
+        if self.nightly {
+            command.arg("+nightly");
+        }
+        command
+    }
+
+    pub fn build(&self) -> Result<PathBuf, Box<dyn Error>> {
+        // Compiler warnings are only shown when the build fails.
+        let output = self
+            .cargo()
+            .args(["build", "--release", "--quiet", "--manifest-path"])
+            .arg(self.dir.join("Cargo.toml"))
+            .stdin(Stdio::null())
+            .output()?;
+        if !output.status.success() {
+            eprint!("{}", String::from_utf8_lossy(&output.stderr));
+            return Err(format!("Cannot build {}", self.dir.display()).into());
+        }
+
+        Ok(self.dir.join("target/release").join(&self.name))
+    }
+
+    // The solvers may look for files next to them, so they are run from their own directory with
+    // the absolute path of the input. Nothing comes on stdin, which they would read instead.
+    fn command(&self, binary: &Path, input: &Path) -> Result<Command, Box<dyn Error>> {
+        let input = fs::canonicalize(input)
+            .map_err(|e| format!("Cannot open {}: {}", input.display(), e))?;
+
+        let mut command = Command::new(binary);
+        command
+            .current_dir(&self.dir)
+            .arg(input)
+            .stdin(Stdio::null());
+        Ok(command)
+    }
+
+    pub fn run(&self, input: &Path) -> Result<Run, Box<dyn Error>> {
+        let binary = self.build()?;
+        let mut command = self.command(&binary, input)?;
+
+        let start = Instant::now();
+        let output = command.output()?;
+        let elapsed = start.elapsed();
+
+        Ok(Run {
+            success: output.status.success(),
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            elapsed,
+        })
+    }
+}
+
+impl Run {
+    // The last line printed, which is where every solver prints its answer.
+    pub fn answer(&self) -> &str {
+        self.stdout
+            .lines()
+            .rev()
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty())
+            .unwrap_or("")
+    }
+
+    // Where the solver panicked, e.g. `src/main.rs:16:42`.
+    pub fn panic_location(&self) -> Option<&str> {
+        let line = self.stderr.lines().find(|l| l.contains("panicked at"))?;
+        let location = line.split("panicked at ").nth(1)?;
+
+        Some(location.trim_end_matches(':').split(", ").last().unwrap())
+    }
+
+    pub fn describe(&self) -> String {
+        match (self.success, self.panic_location()) {
+            (true, _) => format!("ok ({})", self.answer()),
+            (false, Some(location)) => format!("panicked at {}", location),
+            (false, None) => "failed".to_string(),
+        }
+    }
+}
+
+#[test]
+fn test_command() {
+    let solver = solvers(1).unwrap().remove(0);
+    let input = solver.dir.join("Cargo.toml");
+    let command = solver.command(Path::new("day-01"), &input).unwrap();
+
+    let args = command.get_args().collect::<Vec<_>>();
+    assert_eq!(args, [fs::canonicalize(&input).unwrap().as_os_str()]);
+    assert_eq!(command.get_current_dir(), Some(solver.dir.as_path()));
+}
@@ -0,0 +1,127 @@
+pub use serde_json::{json, Value};
+
+use serde_json::Map;
+use std::env;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+
+// Name of the environment variable holding the path of the trace file. Tracing is disabled when
+// it is not set, e.g. `AOC_TRACE=trace.jsonl cargo run < input`.
+pub const TRACE_ENV: &str = "AOC_TRACE";
+
+// Writes one JSON object per line for every state transition of a simulation. Each line holds
+// the day, a step counter, the event name, and the fields given by the simulator.
+pub struct Tracer {
+    day: u32,
+    step: u64,
+    writer: Option<BufWriter<File>>,
+}
+
+impl Tracer {
+    pub fn from_env(day: u32) -> Tracer {
+        let writer = env::var(TRACE_ENV).ok().map(|path| {
+            let file = File::create(&path)
+                .unwrap_or_else(|e| panic!("Cannot create trace file {}: {}", path, e));
+            BufWriter::new(file)
+        });
+
+        Tracer {
+            day,
+            step: 0,
+            writer,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.writer.is_some()
+    }
+
+    // The fields are built lazily so that disabled tracing costs nothing.
+    pub fn record(&mut self, event: &str, fields: impl FnOnce() -> Value) {
+        let Some(writer) = self.writer.as_mut() else {
+            return;
+        };
+
+        let mut object = Map::new();
+        object.insert("day".to_string(), Value::from(self.day));
+        object.insert("step".to_string(), Value::from(self.step));
+        object.insert("event".to_string(), Value::from(event));
+        if let Value::Object(fields) = fields() {
+            object.extend(fields);
+        }
+
+        serde_json::to_writer(&mut *writer, &object).unwrap();
+        writer.write_all(b"\n").unwrap();
+        self.step += 1;
+    }
+}
+
+impl Drop for Tracer {
+    fn drop(&mut self) {
+        if let Some(writer) = self.writer.as_mut() {
+            writer.flush().unwrap();
+        }
+    }
+}
+
+// A parsed trace line.
+#[derive(Debug, Clone)]
+pub struct Event {
+    // The line of the trace file, from 1.
+    pub line: usize,
+    pub day: u32,
+    pub step: u64,
+    pub name: String,
+    pub fields: Value,
+}
+
+impl Event {
+    pub fn get(&self, field: &str) -> &Value {
+        &self.fields[field]
+    }
+
+    pub fn i64(&self, field: &str) -> Result<i64, Box<dyn Error>> {
+        self.get(field).as_i64().ok_or_else(|| self.invalid(field))
+    }
+
+    pub fn invalid(&self, field: &str) -> Box<dyn Error> {
+        format!(
+            "Line {}: invalid field {} in {} event",
+            self.line, field, self.name
+        )
+        .into()
+    }
+}
+
+pub fn read_trace(path: &str) -> Result<Vec<Event>, Box<dyn Error>> {
+    let file = File::open(path)?;
+    let mut events = Vec::new();
+
+    for (n, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields: Value = serde_json::from_str(&line)
+            .map_err(|e| format!("{}:{}: invalid trace line: {}", path, n + 1, e))?;
+        let missing = |field| format!("{}:{}: missing {}", path, n + 1, field);
+        let day = fields["day"].as_u64().ok_or_else(|| missing("day"))? as u32;
+        let step = fields["step"].as_u64().ok_or_else(|| missing("step"))?;
+        let name = fields["event"]
+            .as_str()
+            .ok_or_else(|| missing("event"))?
+            .to_string();
+
+        events.push(Event {
+            line: n + 1,
+            day,
+            step,
+            name,
+            fields,
+        });
+    }
+
+    Ok(events)
+}
@@ -0,0 +1,212 @@
+use aoc::input::Input;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::io::BufRead;
+use std::thread;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Elf {
+    // 1-based, in the order of the input.
+    pub index: usize,
+    pub items: usize,
+    pub calories: i64,
+}
+
+// Elves carrying more calories come first, then the first ones in the input.
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.calories
+            .cmp(&other.calories)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+impl PartialOrd for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// The k best elves seen so far. The worst of them is on top of the heap, ready to be replaced.
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<Elf>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> TopK {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, elf: Elf) {
+        if self.heap.len() == self.k {
+            match self.heap.peek() {
+                Some(Reverse(worst)) if elf > *worst => {
+                    self.heap.pop();
+                }
+                _ => return,
+            }
+        }
+        self.heap.push(Reverse(elf));
+    }
+
+    // Best first.
+    pub fn into_sorted(self) -> Vec<Elf> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(elf)| elf)
+            .collect()
+    }
+}
+
+// Reads the elves one line at a time and only keeps the best ones, so that the size of the input
+// doesn't matter. Elves are numbered from `first_index`.
+pub fn scan_elves(
+    mut reader: impl BufRead,
+    k: usize,
+    first_index: usize,
+) -> Result<(TopK, usize), Box<dyn Error>> {
+    let mut top = TopK::new(k);
+    let mut count = 0;
+    let mut current: Option<Elf> = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        let end = reader.read_line(&mut line)? == 0;
+        let calory = line.trim();
+
+        if calory.is_empty() {
+            if let Some(elf) = current.take() {
+                top.push(elf);
+            }
+            if end {
+                break;
+            }
+            continue;
+        }
+
+        let index = first_index + count;
+        let elf = current.get_or_insert_with(|| {
+            count += 1;
+            Elf {
+                index,
+                items: 0,
+                calories: 0,
+            }
+        });
+        elf.items += 1;
+        elf.calories += calory
+            .parse::<i64>()
+            .map_err(|e| format!("Invalid calories {:?} for elf {}: {}", calory, index, e))?;
+    }
+
+    Ok((top, count))
+}
+
+// The start of the line after the first blank line from `from`, so that no elf is split between
+// two chunks.
+fn next_group_start(bytes: &[u8], from: usize) -> usize {
+    let mut lines = bytes[from..].split_inclusive(|&b| b == b'\n');
+    // `from` can be in the middle of a line.
+    let mut start = from + lines.next().map_or(0, |line| line.len());
+
+    for line in lines {
+        start += line.len();
+        if line.iter().all(|b| b.is_ascii_whitespace()) {
+            return start;
+        }
+    }
+    bytes.len()
+}
+
+// Scans chunks of the input in parallel. The chunks end on blank lines, and their elves are
+// numbered once every chunk knows how many elves it has.
+pub fn scan_chunks(input: &Input, k: usize, jobs: usize) -> Result<(TopK, usize), Box<dyn Error>> {
+    let bytes = input.bytes();
+    let mut bounds = vec![0];
+    for job in 1..jobs {
+        let start = next_group_start(bytes, (bytes.len() * job / jobs).max(bounds[job - 1]));
+        bounds.push(start);
+    }
+    bounds.push(bytes.len());
+
+    let results = thread::scope(|scope| {
+        let handles = bounds
+            .windows(2)
+            .map(|chunk| {
+                let chunk = &bytes[chunk[0]..chunk[1]];
+                scope.spawn(move || scan_elves(chunk, k, 1).map_err(|e| e.to_string()))
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Result<Vec<_>, _>>()
+    })?;
+
+    let mut top = TopK::new(k);
+    let mut count = 0;
+    for (chunk_top, chunk_count) in results {
+        for Reverse(mut elf) in chunk_top.heap {
+            elf.index += count;
+            top.push(elf);
+        }
+        count += chunk_count;
+    }
+
+    Ok((top, count))
+}
+
+pub fn parse_elves(input: &Input) -> Result<Vec<Elf>, Box<dyn Error>> {
+    input
+        .groups()
+        .enumerate()
+        .map(|(i, elve_bag)| {
+            let calories = elve_bag
+                .iter()
+                .flat_map(|line| line.split_whitespace())
+                .map(|calory| {
+                    calory.parse::<i64>().map_err(|e| {
+                        format!("Invalid calories {:?} for elf {}: {}", calory, i + 1, e)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(Elf {
+                index: i + 1,
+                items: calories.len(),
+                calories: calories.iter().sum(),
+            })
+        })
+        .collect()
+}
+
+pub fn top_elves(elves: &[Elf], n: usize) -> Vec<Elf> {
+    let mut top = TopK::new(n);
+    for elf in elves {
+        top.push(elf.clone());
+    }
+    top.into_sorted()
+}
+
+#[test]
+fn test_scan_chunks() {
+    let text = "1000\n2000\n\n\n4000\r\n\r\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+    let input = Input::from_bytes(text.as_bytes().to_vec()).unwrap();
+    let (top, count) = scan_elves(text.as_bytes(), 5, 1).unwrap();
+    let expected = top.into_sorted();
+    assert_eq!(count, 5);
+    assert_eq!(expected[0].index, 4);
+
+    for jobs in 1..8 {
+        let (top, count) = scan_chunks(&input, 5, jobs).unwrap();
+        assert_eq!(count, 5);
+        assert_eq!(top.into_sorted(), expected);
+    }
+}
@@ -1,25 +1,160 @@
-#![feature(binary_heap_into_iter_sorted)]
-
-use std::collections::BinaryHeap;
+use aoc::args::{take_flag, take_option, take_parsed};
+use aoc::input::{Input, Source};
+use day_01::{parse_elves, scan_chunks, scan_elves, top_elves, Elf};
+use serde_json::json;
+use std::env;
 use std::error::Error;
 use std::fs;
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let text = fs::read_to_string("./input1.txt")?;
-
-    let result: i64 = text
-        .split("\n\n")
-        .map(|elve_bag| {
-            elve_bag
-                .split_whitespace()
-                .fold(0, |acc, calory| acc + calory.parse::<i64>().unwrap())
+fn median(sorted: &[i64]) -> f64 {
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] + sorted[middle]) as f64 / 2.0
+    } else {
+        sorted[middle] as f64
+    }
+}
+
+// Nearest-rank percentile: the smallest value with at least `p`% of the values at or below it.
+fn percentile(sorted: &[i64], p: usize) -> i64 {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+fn print_statistics(name: &str, mut values: Vec<i64>) {
+    values.sort();
+    println!(
+        "{}: min {}, p25 {}, median {}, p75 {}, p90 {}, max {}",
+        name,
+        values[0],
+        percentile(&values, 25),
+        median(&values),
+        percentile(&values, 75),
+        percentile(&values, 90),
+        values[values.len() - 1]
+    );
+}
+
+// The rank of every elf, 1 being the elf carrying the most calories.
+fn ranks(elves: &[Elf]) -> Vec<usize> {
+    let mut ranks = vec![0; elves.len()];
+    for (rank, elf) in top_elves(elves, elves.len()).iter().enumerate() {
+        ranks[elf.index - 1] = rank + 1;
+    }
+    ranks
+}
+
+fn to_csv(elves: &[Elf]) -> String {
+    let mut csv = "elf,items,calories,rank\n".to_string();
+    for (elf, rank) in elves.iter().zip(ranks(elves)) {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            elf.index, elf.items, elf.calories, rank
+        ));
+    }
+    csv
+}
+
+fn to_json(elves: &[Elf]) -> String {
+    let rows = elves
+        .iter()
+        .zip(ranks(elves))
+        .map(|(elf, rank)| {
+            json!({
+                "elf": elf.index,
+                "items": elf.items,
+                "calories": elf.calories,
+                "rank": rank,
+            })
         })
-        .collect::<BinaryHeap<i64>>()
-        .into_iter_sorted()
-        .take(3)
-        .sum();
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&rows).unwrap() + "\n"
+}
+
+fn print_ranking(ranking: &[Elf]) {
+    println!("Top {}:", ranking.len());
+    println!(
+        "{:>4}  {:>5}  {:>5}  {:>8}",
+        "rank", "elf", "items", "calories"
+    );
+    for (rank, elf) in ranking.iter().enumerate() {
+        println!(
+            "{:>4}  {:>5}  {:>5}  {:>8}",
+            rank + 1,
+            elf.index,
+            elf.items,
+            elf.calories
+        );
+    }
+    println!();
+}
+
+// Both parts only need the three best elves.
+fn print_answers(ranking: &[Elf]) {
+    println!("Part 1: {}", ranking[0].calories);
+    println!(
+        "Part 2: {}",
+        ranking.iter().take(3).map(|e| e.calories).sum::<i64>()
+    );
+}
+
+// Usage: day-01 [--top <n>] [--csv <file>] [--json <file>] [<input> | - | --example]
+//        day-01 [--top <n>] (--stream | --jobs <n>) [<input> | - | --example]
+//
+// The report needs every elf in memory, --stream reads the input as it goes and only keeps the
+// best elves, and --jobs does the same over chunks of the input in parallel.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let top = take_parsed::<usize>(&mut args, "--top")?.unwrap_or(3);
+    let csv = take_option(&mut args, "--csv")?;
+    let json = take_option(&mut args, "--json")?;
+    let jobs = take_parsed::<usize>(&mut args, "--jobs")?;
+    let stream = take_flag(&mut args, "--stream");
+    let source = Source::from_remaining_args(args)?;
+
+    if stream || jobs.is_some() {
+        if csv.is_some() || json.is_some() {
+            return Err("--csv and --json need every elf, not just the best ones".into());
+        }
+
+        let k = top.max(3);
+        let (best, count) = match jobs {
+            Some(jobs) => scan_chunks(&Input::from_source(source)?, k, jobs.max(1))?,
+            None => scan_elves(source.reader()?, k, 1)?,
+        };
+        if count == 0 {
+            return Err("No elf in the input".into());
+        }
+
+        let ranking = best.into_sorted();
+        println!("{} elves\n", count);
+        print_ranking(&ranking[..top.min(ranking.len())]);
+        print_answers(&ranking);
+
+        return Ok(());
+    }
+
+    let input = Input::from_source(source)?;
+    let elves = parse_elves(&input)?;
+    if elves.is_empty() {
+        return Err("No elf in the input".into());
+    }
+
+    println!("{} elves\n", elves.len());
+    print_ranking(&top_elves(&elves, top));
+
+    print_statistics("Calories", elves.iter().map(|e| e.calories).collect());
+    print_statistics("Items", elves.iter().map(|e| e.items as i64).collect());
+
+    if let Some(path) = csv {
+        fs::write(&path, to_csv(&elves)).map_err(|e| format!("Cannot write {}: {}", path, e))?;
+    }
+    if let Some(path) = json {
+        fs::write(&path, to_json(&elves)).map_err(|e| format!("Cannot write {}: {}", path, e))?;
+    }
 
-    println!("{result:#?}");
+    println!();
+    print_answers(&top_elves(&elves, 3));
 
     Ok(())
 }
@@ -0,0 +1,94 @@
+use aoc::input::Input;
+use rules::{Move, Outcome, Rules};
+use strategy::Meaning;
+
+pub mod rules;
+pub mod strategy;
+
+// What the second column of the strategy guide means: our move in part 1, the outcome the round
+// must have in part 2.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Interpretation {
+    Move,
+    Outcome,
+}
+
+impl Interpretation {
+    pub fn name(&self) -> &str {
+        match self {
+            Interpretation::Move => "move",
+            Interpretation::Outcome => "outcome",
+        }
+    }
+}
+
+pub struct Round {
+    pub opponent: Move,
+    pub response: Move,
+    pub outcome: Outcome,
+}
+
+impl Round {
+    pub fn score(&self, rules: &Rules) -> i64 {
+        rules.shape_score(self.response) + rules.outcome_score(self.outcome)
+    }
+}
+
+// The opponent's move and our letter.
+pub fn parse_line<'a>(rules: &Rules, line: &'a str) -> Result<(Move, &'a str), String> {
+    let letters = line.split_whitespace().collect::<Vec<_>>();
+    let [letter1, letter2] = letters[..] else {
+        return Err(format!("Expected two letters, found {:?}", line));
+    };
+
+    let opponent = rules
+        .opponent_move_from_letter(letter1)
+        .ok_or(format!("Unknown opponent move {:?}", letter1))?;
+    Ok((opponent, letter2))
+}
+
+pub fn play(rules: &Rules, line: &str, interpretation: Interpretation) -> Result<Round, String> {
+    let (opponent, letter) = parse_line(rules, line)?;
+    let meaning = match interpretation {
+        Interpretation::Move => rules.move_from_letter(letter).map(Meaning::Move),
+        Interpretation::Outcome => rules.outcome_from_letter(letter).map(Meaning::Outcome),
+    }
+    .ok_or(format!("Unknown {} {:?}", interpretation.name(), letter))?;
+
+    let (response, outcome) = strategy::respond(rules, opponent, meaning).ok_or(format!(
+        "No move can {} against {}",
+        meaning.name(rules),
+        rules.name(opponent)
+    ))?;
+    Ok(Round {
+        opponent,
+        response,
+        outcome,
+    })
+}
+
+// The guide's rounds, as the opponent's move and our letter.
+pub fn parse_guide<'a>(rules: &Rules, input: &'a Input) -> Result<Vec<(Move, &'a str)>, String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(n, line)| parse_line(rules, line).map_err(|e| format!("Line {}: {}", n + 1, e)))
+        .collect()
+}
+
+// Every round of the guide, with the second column read one way.
+pub fn play_guide(
+    rules: &Rules,
+    input: &Input,
+    interpretation: Interpretation,
+) -> Result<Vec<Round>, String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(n, line)| {
+            play(rules, line, interpretation).map_err(|e| format!("Line {}: {}", n + 1, e))
+        })
+        .collect()
+}
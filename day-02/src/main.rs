@@ -0,0 +1,174 @@
+use aoc::args::{take_flag, take_option, take_parsed};
+use aoc::input::Input;
+use day_02::rules::{Move, Rules, DEFAULT_RULES, OUTCOMES};
+use day_02::strategy::{self, Meaning};
+use day_02::{parse_guide, play_guide, Interpretation, Round};
+use std::env;
+use std::error::Error;
+use std::fs;
+
+fn print_rounds(rules: &Rules, rounds: &[Round]) {
+    println!(
+        "{:>5}  {:<8}  {:<8}  {:<7}  {:>5}  {:>7}  {:>5}  {:>6}",
+        "round", "opponent", "response", "outcome", "shape", "outcome", "score", "total"
+    );
+
+    let mut total = 0;
+    for (n, round) in rounds.iter().enumerate() {
+        total += round.score(rules);
+        println!(
+            "{:>5}  {:<8}  {:<8}  {:<7}  {:>5}  {:>7}  {:>5}  {:>6}",
+            n + 1,
+            rules.name(round.opponent),
+            rules.name(round.response),
+            format!("{:?}", round.outcome),
+            rules.shape_score(round.response),
+            rules.outcome_score(round.outcome),
+            round.score(rules),
+            total
+        );
+    }
+    println!();
+}
+
+// Prints a guide against the same opponent moves, scoring the most or exactly the total given.
+// The total goes to stderr so that the guide can be fed back to the solver.
+fn optimize(
+    rules: &Rules,
+    guide: &[(Move, &str)],
+    target: &str,
+    show_rounds: bool,
+) -> Result<(), Box<dyn Error>> {
+    let opponents = guide.iter().map(|(m, _)| *m).collect::<Vec<_>>();
+    let responses = match target {
+        "max" => strategy::best_responses(rules, &opponents).0,
+        _ => {
+            let total = target
+                .parse()
+                .map_err(|_| format!("Invalid target {}, expected max or a total", target))?;
+            strategy::responses_for_total(rules, &opponents, total)
+                .ok_or(format!("No responses score a total of {}", total))?
+        }
+    };
+
+    let rounds = opponents
+        .iter()
+        .zip(responses)
+        .map(|(&opponent, response)| Round {
+            opponent,
+            response,
+            outcome: rules.outcome(opponent, response),
+        })
+        .collect::<Vec<_>>();
+    if show_rounds {
+        print_rounds(rules, &rounds);
+    } else {
+        for round in &rounds {
+            println!(
+                "{} {}",
+                rules.opponent_letter(round.opponent),
+                rules.letter(round.response)
+            );
+        }
+    }
+    eprintln!(
+        "Total: {}",
+        rounds.iter().map(|r| r.score(rules)).sum::<i64>()
+    );
+
+    Ok(())
+}
+
+// Prints the meanings of the second column's letters under which the guide scores `total`.
+fn infer(rules: &Rules, guide: &[(Move, &str)], interpretation: Interpretation, total: i64) {
+    let meanings = match interpretation {
+        Interpretation::Move => rules.moves().map(Meaning::Move).collect::<Vec<_>>(),
+        Interpretation::Outcome => OUTCOMES.map(|(_, o)| Meaning::Outcome(o)).to_vec(),
+    };
+
+    println!(
+        "Second column as the {}, scoring {}:",
+        interpretation.name(),
+        total
+    );
+    let found = strategy::infer_meanings(rules, guide, &meanings, total);
+    if found.is_empty() {
+        println!("  no mapping of the letters");
+    }
+    for mapping in found {
+        let mapping = mapping
+            .iter()
+            .map(|(letter, meaning)| format!("{} = {}", letter, meaning.name(rules)))
+            .collect::<Vec<_>>();
+        println!("  {}", mapping.join(", "));
+    }
+}
+
+// Usage: day-02 [--interpretation move|outcome|both] [--rounds] [--rules <file>]
+//               [--optimize max|<total> | --infer <total>] [<input> | - | --example]
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let interpretations = match take_option(&mut args, "--interpretation")?.as_deref() {
+        Some("move") => vec![Interpretation::Move],
+        Some("outcome") => vec![Interpretation::Outcome],
+        Some("both") | None => vec![Interpretation::Move, Interpretation::Outcome],
+        Some(other) => {
+            return Err(format!(
+                "Unknown interpretation {}, expected move, outcome or both",
+                other
+            )
+            .into())
+        }
+    };
+    let show_rounds = take_flag(&mut args, "--rounds");
+    let rules = match take_option(&mut args, "--rules")? {
+        Some(path) => {
+            let text =
+                fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+            Rules::parse(&text).map_err(|e| format!("{}: {}", path, e))?
+        }
+        None => Rules::parse(DEFAULT_RULES)?,
+    };
+    let target = take_option(&mut args, "--optimize")?;
+    let infer_total = take_parsed::<i64>(&mut args, "--infer")?;
+    let input = Input::from_remaining_args(args)?;
+
+    if let Some(target) = target {
+        return optimize(&rules, &parse_guide(&rules, &input)?, &target, show_rounds);
+    }
+    if let Some(total) = infer_total {
+        let guide = parse_guide(&rules, &input)?;
+        for interpretation in interpretations {
+            infer(&rules, &guide, interpretation, total);
+        }
+        return Ok(());
+    }
+
+    let mut totals = Vec::new();
+    for interpretation in interpretations {
+        let rounds = play_guide(&rules, &input, interpretation)?;
+
+        if show_rounds {
+            println!("Second column as the {}:", interpretation.name());
+            print_rounds(&rules, &rounds);
+        }
+
+        let part = match interpretation {
+            Interpretation::Move => 1,
+            Interpretation::Outcome => 2,
+        };
+        let total = rounds.iter().map(|r| r.score(&rules)).sum::<i64>();
+        totals.push(format!(
+            "Part {} (second column as the {}): {}",
+            part,
+            interpretation.name(),
+            total
+        ));
+    }
+
+    for total in totals {
+        println!("{}", total);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+// The rules of the puzzle, used when no other rules are given.
+pub const DEFAULT_RULES: &str = include_str!("../rules/rps.txt");
+
+// A move of the game, as its index in the rules.
+pub type Move = usize;
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+pub const OUTCOMES: [(&str, Outcome); 3] = [
+    ("lose", Outcome::Lose),
+    ("draw", Outcome::Draw),
+    ("win", Outcome::Win),
+];
+
+struct MoveRule {
+    name: String,
+    score: i64,
+    opponent_letter: String,
+    letter: String,
+}
+
+// A game loaded from a rules file, see `rules/rps.txt` for the format. Any number of moves
+// works, as long as every two different moves have one beating the other.
+pub struct Rules {
+    moves: Vec<MoveRule>,
+    // `beats[a][b]` when `a` beats `b`.
+    beats: Vec<Vec<bool>>,
+    // Score and letter, by `Outcome`.
+    outcomes: [Option<(i64, String)>; 3],
+}
+
+fn parse_score(score: &str) -> Result<i64, String> {
+    score
+        .parse()
+        .map_err(|e| format!("Invalid score {:?}: {}", score, e))
+}
+
+fn check_unique<'a>(what: &str, values: impl Iterator<Item = &'a str>) -> Result<(), String> {
+    let mut seen = HashSet::new();
+    for value in values {
+        if !seen.insert(value) {
+            return Err(format!("{} {} is used twice", what, value));
+        }
+    }
+    Ok(())
+}
+
+impl Rules {
+    pub fn parse(text: &str) -> Result<Rules, String> {
+        let mut rules = Rules {
+            moves: Vec::new(),
+            beats: Vec::new(),
+            outcomes: [None, None, None],
+        };
+        let mut beats = Vec::new();
+
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let result = match words[..] {
+                ["move", name, score, opponent_letter, letter] => parse_score(score).map(|score| {
+                    rules.moves.push(MoveRule {
+                        name: name.to_string(),
+                        score,
+                        opponent_letter: opponent_letter.to_string(),
+                        letter: letter.to_string(),
+                    })
+                }),
+                ["outcome", outcome, score, letter] => {
+                    match OUTCOMES.iter().find(|(name, _)| *name == outcome) {
+                        Some((_, outcome)) => parse_score(score).map(|score| {
+                            rules.outcomes[*outcome as usize] = Some((score, letter.to_string()))
+                        }),
+                        None => Err(format!("Unknown outcome {:?}", outcome)),
+                    }
+                }
+                _ => match line.split_once(" beats ") {
+                    Some((winner, losers)) => {
+                        for loser in losers.split(',') {
+                            beats.push((n, winner.trim().to_string(), loser.trim().to_string()));
+                        }
+                        Ok(())
+                    }
+                    None => Err(format!("Cannot parse {:?}", line)),
+                },
+            };
+            result.map_err(|e| format!("Line {}: {}", n + 1, e))?;
+        }
+
+        rules.beats = vec![vec![false; rules.moves.len()]; rules.moves.len()];
+        for (n, winner, loser) in beats {
+            let find = |name: &str| {
+                rules
+                    .find_move(name)
+                    .ok_or(format!("Line {}: Unknown move {:?}", n + 1, name))
+            };
+            let (winner, loser) = (find(&winner)?, find(&loser)?);
+            rules.beats[winner][loser] = true;
+        }
+
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.moves.is_empty() {
+            return Err("The rules have no move".to_string());
+        }
+        check_unique("Move", self.moves.iter().map(|m| m.name.as_str()))?;
+        check_unique(
+            "Opponent letter",
+            self.moves.iter().map(|m| m.opponent_letter.as_str()),
+        )?;
+        check_unique("Letter", self.moves.iter().map(|m| m.letter.as_str()))?;
+
+        for (name, outcome) in OUTCOMES {
+            if self.outcomes[outcome as usize].is_none() {
+                return Err(format!("No score for the {} outcome", name));
+            }
+        }
+        check_unique(
+            "Outcome letter",
+            self.outcomes.iter().flatten().map(|(_, l)| l.as_str()),
+        )?;
+
+        // The beats-relation must be a tournament, so that every round has a single outcome.
+        for a in 0..self.moves.len() {
+            if self.beats[a][a] {
+                return Err(format!("{} beats itself", self.name(a)));
+            }
+            for b in a + 1..self.moves.len() {
+                match (self.beats[a][b], self.beats[b][a]) {
+                    (true, true) => {
+                        return Err(format!(
+                            "{} and {} beat each other",
+                            self.name(a),
+                            self.name(b)
+                        ))
+                    }
+                    (false, false) => {
+                        return Err(format!(
+                            "Neither {} nor {} beats the other",
+                            self.name(a),
+                            self.name(b)
+                        ))
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn find_move(&self, name: &str) -> Option<Move> {
+        self.moves.iter().position(|m| m.name == name)
+    }
+
+    pub fn moves(&self) -> Range<Move> {
+        0..self.moves.len()
+    }
+
+    pub fn name(&self, m: Move) -> &str {
+        &self.moves[m].name
+    }
+
+    pub fn shape_score(&self, m: Move) -> i64 {
+        self.moves[m].score
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> i64 {
+        self.outcomes[outcome as usize].as_ref().unwrap().0
+    }
+
+    pub fn opponent_letter(&self, m: Move) -> &str {
+        &self.moves[m].opponent_letter
+    }
+
+    pub fn letter(&self, m: Move) -> &str {
+        &self.moves[m].letter
+    }
+
+    pub fn opponent_move_from_letter(&self, letter: &str) -> Option<Move> {
+        self.moves.iter().position(|m| m.opponent_letter == letter)
+    }
+
+    pub fn move_from_letter(&self, letter: &str) -> Option<Move> {
+        self.moves.iter().position(|m| m.letter == letter)
+    }
+
+    pub fn outcome_from_letter(&self, letter: &str) -> Option<Outcome> {
+        OUTCOMES
+            .iter()
+            .map(|(_, outcome)| *outcome)
+            .find(|outcome| self.outcomes[*outcome as usize].as_ref().unwrap().1 == letter)
+    }
+
+    // The outcome of the round for us.
+    pub fn outcome(&self, opponent: Move, response: Move) -> Outcome {
+        if self.beats[opponent][response] {
+            Outcome::Lose
+        } else if self.beats[response][opponent] {
+            Outcome::Win
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    // The move giving the wanted outcome. With more than three moves several moves can, and the
+    // one with the best shape score is played.
+    pub fn move_for_outcome(&self, opponent: Move, outcome: Outcome) -> Option<Move> {
+        self.moves()
+            .filter(|&m| self.outcome(opponent, m) == outcome)
+            .max_by_key(|&m| self.shape_score(m))
+    }
+}
+
+#[test]
+fn test_rules() {
+    let rules = Rules::parse(DEFAULT_RULES).unwrap();
+    let rock = rules.opponent_move_from_letter("A").unwrap();
+    let paper = rules.move_from_letter("Y").unwrap();
+    assert_eq!(rules.outcome(rock, paper), Outcome::Win);
+    assert_eq!(
+        rules.move_for_outcome(rock, Outcome::Lose),
+        rules.move_from_letter("Z")
+    );
+
+    let rpsls = Rules::parse(include_str!("../rules/rpsls.txt")).unwrap();
+    let spock = rpsls.move_from_letter("Z").unwrap();
+    let lizard = rpsls.move_from_letter("Y").unwrap();
+    assert_eq!(rpsls.outcome(spock, lizard), Outcome::Win);
+
+    let not_a_tournament = DEFAULT_RULES.replace("Scissors beats Paper", "");
+    let error = Rules::parse(&not_a_tournament).err().unwrap();
+    assert!(error.contains("Neither Paper nor Scissors"));
+}
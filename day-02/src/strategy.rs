@@ -0,0 +1,185 @@
+use crate::rules::{Move, Outcome, Rules, OUTCOMES};
+use std::collections::{BTreeSet, HashMap};
+
+// What a letter of the second column of the guide stands for.
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum Meaning {
+    Move(Move),
+    Outcome(Outcome),
+}
+
+impl Meaning {
+    pub fn name<'a>(&self, rules: &'a Rules) -> &'a str {
+        match self {
+            Meaning::Move(m) => rules.name(*m),
+            Meaning::Outcome(outcome) => OUTCOMES.iter().find(|(_, o)| o == outcome).unwrap().0,
+        }
+    }
+}
+
+// Our move and the outcome of the round, None when no move gives the wanted outcome.
+pub fn respond(rules: &Rules, opponent: Move, meaning: Meaning) -> Option<(Move, Outcome)> {
+    match meaning {
+        Meaning::Move(response) => Some((response, rules.outcome(opponent, response))),
+        Meaning::Outcome(outcome) => rules
+            .move_for_outcome(opponent, outcome)
+            .map(|response| (response, outcome)),
+    }
+}
+
+pub fn round_score(rules: &Rules, opponent: Move, response: Move) -> i64 {
+    rules.shape_score(response) + rules.outcome_score(rules.outcome(opponent, response))
+}
+
+// The responses scoring the most, and their total.
+pub fn best_responses(rules: &Rules, opponents: &[Move]) -> (Vec<Move>, i64) {
+    let responses = opponents
+        .iter()
+        .map(|&opponent| {
+            rules
+                .moves()
+                .max_by_key(|&m| round_score(rules, opponent, m))
+                .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let total = opponents
+        .iter()
+        .zip(&responses)
+        .map(|(&opponent, &response)| round_score(rules, opponent, response))
+        .sum();
+
+    (responses, total)
+}
+
+// Responses scoring exactly `total`, if there are any. The totals reachable after each round are
+// worked out first, then the responses are picked from the last round back.
+pub fn responses_for_total(rules: &Rules, opponents: &[Move], total: i64) -> Option<Vec<Move>> {
+    // `reachable[i][t - lows[i]]` when the first `i` rounds can score `t`.
+    let mut lows = vec![0];
+    let mut reachable = vec![vec![true]];
+    for &opponent in opponents {
+        let scores = rules
+            .moves()
+            .map(|m| round_score(rules, opponent, m))
+            .collect::<Vec<_>>();
+        let min = *scores.iter().min().unwrap();
+        let max = *scores.iter().max().unwrap();
+
+        let previous = reachable.last().unwrap();
+        let mut next = vec![false; previous.len() + (max - min) as usize];
+        for (t, _) in previous.iter().enumerate().filter(|(_, r)| **r) {
+            for score in &scores {
+                next[t + (score - min) as usize] = true;
+            }
+        }
+        lows.push(lows.last().unwrap() + min);
+        reachable.push(next);
+    }
+
+    let is_reachable = |rounds: usize, t: i64| {
+        let t = t - lows[rounds];
+        t >= 0 && reachable[rounds].get(t as usize) == Some(&true)
+    };
+    if !is_reachable(opponents.len(), total) {
+        return None;
+    }
+
+    let mut responses = vec![0; opponents.len()];
+    let mut left = total;
+    for (i, &opponent) in opponents.iter().enumerate().rev() {
+        let response = rules
+            .moves()
+            .find(|&m| is_reachable(i, left - round_score(rules, opponent, m)))
+            .unwrap();
+        responses[i] = response;
+        left -= round_score(rules, opponent, response);
+    }
+
+    Some(responses)
+}
+
+// All the ways of picking `k` different items out of `n`, in order.
+fn arrangements(k: usize, n: usize) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return vec![Vec::new()];
+    }
+
+    let mut result = Vec::new();
+    for arrangement in arrangements(k - 1, n) {
+        for i in 0..n {
+            if !arrangement.contains(&i) {
+                let mut arrangement = arrangement.clone();
+                arrangement.push(i);
+                result.push(arrangement);
+            }
+        }
+    }
+    result
+}
+
+// Every way of giving the letters of the second column different meanings among `meanings`
+// for which the guide scores `total`. The rounds are the opponent's move and our letter.
+pub fn infer_meanings<'a>(
+    rules: &Rules,
+    rounds: &[(Move, &'a str)],
+    meanings: &[Meaning],
+    total: i64,
+) -> Vec<Vec<(&'a str, Meaning)>> {
+    let mut counts = HashMap::new();
+    for round in rounds {
+        *counts.entry(*round).or_insert(0) += 1;
+    }
+    let letters = rounds
+        .iter()
+        .map(|(_, letter)| *letter)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    let mut found = Vec::new();
+    for arrangement in arrangements(letters.len(), meanings.len()) {
+        let meaning_of =
+            |letter: &str| meanings[arrangement[letters.binary_search(&letter).unwrap()]];
+
+        let scores = counts
+            .iter()
+            .map(|(&(opponent, letter), count)| {
+                respond(rules, opponent, meaning_of(letter))
+                    .map(|(response, _)| count * round_score(rules, opponent, response))
+            })
+            .collect::<Option<Vec<_>>>();
+        if scores.is_some_and(|scores| scores.iter().sum::<i64>() == total) {
+            found.push(letters.iter().map(|&l| (l, meaning_of(l))).collect());
+        }
+    }
+    found
+}
+
+#[test]
+fn test_strategy() {
+    let rules = Rules::parse(crate::rules::DEFAULT_RULES).unwrap();
+    let [a, b, c] = [0, 1, 2];
+
+    let (responses, total) = best_responses(&rules, &[a, b, c]);
+    assert_eq!((responses, total), (vec![b, c, a], 24));
+    for target in [6, 10, 15, 24] {
+        let responses = responses_for_total(&rules, &[a, b, c], target).unwrap();
+        let score = [a, b, c]
+            .iter()
+            .zip(&responses)
+            .map(|(&o, &r)| round_score(&rules, o, r))
+            .sum::<i64>();
+        assert_eq!(score, target);
+    }
+    assert_eq!(responses_for_total(&rules, &[a, b, c], 5), None);
+    assert_eq!(responses_for_total(&rules, &[a, b, c], 25), None);
+
+    let guide = [(a, "Y"), (b, "X"), (c, "Z")];
+    let outcomes = OUTCOMES.map(|(_, o)| Meaning::Outcome(o));
+    let found = infer_meanings(&rules, &guide, &outcomes, 12);
+    assert!(found.contains(&vec![
+        ("X", Meaning::Outcome(Outcome::Lose)),
+        ("Y", Meaning::Outcome(Outcome::Draw)),
+        ("Z", Meaning::Outcome(Outcome::Win))
+    ]));
+}
@@ -0,0 +1,174 @@
+use std::collections::{HashMap, HashSet};
+
+// The items of the puzzle, in priority order.
+pub const DEFAULT_RANGES: &str = "a-z,A-Z";
+
+// The items rucksacks can hold and their priorities. Items are numbered in the order they are
+// given, and that number is their bit in the sets.
+pub struct Alphabet {
+    items: Vec<(char, i64)>,
+    indices: HashMap<char, usize>,
+}
+
+// A set of items, as a bitset when the alphabet fits in 64 bits.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ItemSet {
+    Bits(u64),
+    Hashed(HashSet<usize>),
+}
+
+impl ItemSet {
+    pub fn intersect(self, other: &ItemSet) -> ItemSet {
+        match (self, other) {
+            (ItemSet::Bits(a), ItemSet::Bits(b)) => ItemSet::Bits(a & b),
+            (ItemSet::Hashed(a), ItemSet::Hashed(b)) => {
+                ItemSet::Hashed(a.intersection(b).copied().collect())
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    // The item numbers, in order.
+    pub fn items(&self) -> Vec<usize> {
+        match self {
+            ItemSet::Bits(bits) => (0..64).filter(|i| bits & 1 << i != 0).collect(),
+            ItemSet::Hashed(items) => {
+                let mut items = items.iter().copied().collect::<Vec<_>>();
+                items.sort();
+                items
+            }
+        }
+    }
+
+    // The item of the set, if it has exactly one.
+    pub fn single(&self) -> Option<usize> {
+        match self {
+            ItemSet::Bits(bits) => (bits.count_ones() == 1).then(|| bits.trailing_zeros() as usize),
+            ItemSet::Hashed(items) if items.len() == 1 => items.iter().next().copied(),
+            ItemSet::Hashed(_) => None,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self {
+            ItemSet::Bits(bits) => *bits == 0,
+            ItemSet::Hashed(items) => items.is_empty(),
+        }
+    }
+}
+
+impl Alphabet {
+    fn new(items: Vec<(char, i64)>) -> Result<Alphabet, String> {
+        let mut indices = HashMap::new();
+        for (index, (item, _)) in items.iter().enumerate() {
+            if indices.insert(*item, index).is_some() {
+                return Err(format!("Item {:?} is given twice", item));
+            }
+        }
+        if items.is_empty() {
+            return Err("The alphabet has no item".to_string());
+        }
+
+        Ok(Alphabet { items, indices })
+    }
+
+    // Ranges like "a-z,A-Z,0-9" or single items, with the priorities counting up from 1.
+    pub fn from_ranges(ranges: &str) -> Result<Alphabet, String> {
+        let mut items = Vec::new();
+        for range in ranges.split(',') {
+            let chars = range.trim().chars().collect::<Vec<_>>();
+            let (first, last) = match chars[..] {
+                [item] => (item, item),
+                [first, '-', last] if first <= last => (first, last),
+                _ => return Err(format!("Invalid range {:?}, expected a-z or a", range)),
+            };
+            items.extend(first..=last);
+        }
+
+        Alphabet::new((1..).zip(items).map(|(p, item)| (item, p)).collect())
+    }
+
+    // One "<item> <priority>" per line, blank lines and lines starting with # ignored.
+    pub fn from_table(text: &str) -> Result<Alphabet, String> {
+        let mut items = Vec::new();
+        for (n, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let words = line.split_whitespace().collect::<Vec<_>>();
+            let item = match words[..] {
+                [item, priority] if item.chars().count() == 1 => priority
+                    .parse()
+                    .map(|p| (item.chars().next().unwrap(), p))
+                    .map_err(|e| format!("Invalid priority {:?}: {}", priority, e)),
+                _ => Err(format!(
+                    "Expected an item and its priority, found {:?}",
+                    line
+                )),
+            };
+            items.push(item.map_err(|e| format!("Line {}: {}", n + 1, e))?);
+        }
+
+        Alphabet::new(items)
+    }
+
+    pub fn set(&self, items: &str) -> Result<ItemSet, String> {
+        let mut indices = items.chars().enumerate().map(|(i, c)| {
+            self.indices.get(&c).copied().ok_or(format!(
+                "{:?} in column {} is not an item",
+                c,
+                i + 1
+            ))
+        });
+
+        if self.items.len() <= 64 {
+            indices
+                .try_fold(0, |bits, index| Ok(bits | 1 << index?))
+                .map(ItemSet::Bits)
+        } else {
+            indices.collect::<Result<_, _>>().map(ItemSet::Hashed)
+        }
+    }
+
+    pub fn item(&self, index: usize) -> char {
+        self.items[index].0
+    }
+
+    pub fn priority(&self, index: usize) -> i64 {
+        self.items[index].1
+    }
+
+    pub fn list(&self, set: &ItemSet) -> String {
+        let items = set.items().into_iter().map(|i| self.item(i).to_string());
+        items.collect::<Vec<_>>().join(", ")
+    }
+}
+
+#[test]
+fn test_alphabet() {
+    let letters = Alphabet::from_ranges(DEFAULT_RANGES).unwrap();
+    let set = letters.set("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+    assert!(matches!(set, ItemSet::Bits(_)));
+    let shared = set.intersect(&letters.set("jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL").unwrap());
+    assert_eq!(letters.list(&shared), "f, r, s, F, M");
+    assert_eq!(
+        letters.priority(letters.set("Z").unwrap().single().unwrap()),
+        52
+    );
+    assert!(letters.set("a1").is_err());
+
+    let wide = Alphabet::from_ranges("a-z,A-Z,0-9,α-ω").unwrap();
+    let set = wide.set("aω1").unwrap();
+    assert!(matches!(set, ItemSet::Hashed(_)));
+    let shared = set.intersect(&wide.set("ωb").unwrap());
+    assert_eq!(wide.priority(shared.single().unwrap()), 87);
+
+    let table = Alphabet::from_table("# digits\n0 10\n1 20\n").unwrap();
+    assert_eq!(
+        table.priority(table.set("1").unwrap().single().unwrap()),
+        20
+    );
+    assert!(Alphabet::from_table("0 10\n0 20\n").is_err());
+}
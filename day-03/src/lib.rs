@@ -0,0 +1,78 @@
+use alphabet::{Alphabet, ItemSet};
+
+pub mod alphabet;
+
+// The items in all the sets.
+fn shared(sets: impl Iterator<Item = ItemSet>) -> ItemSet {
+    sets.reduce(|shared, set| shared.intersect(&set)).unwrap()
+}
+
+// A rucksack split in `count` compartments of the same size.
+fn compartments(rucksack: &str, count: usize) -> impl Iterator<Item = &str> {
+    let mut bounds = rucksack.char_indices().map(|(i, _)| i).collect::<Vec<_>>();
+    let size = bounds.len() / count;
+    bounds.push(rucksack.len());
+    (0..count).map(move |i| &rucksack[bounds[i * size]..bounds[(i + 1) * size]])
+}
+
+// The item found in every compartment of a rucksack.
+pub fn misplaced_item(
+    alphabet: &Alphabet,
+    rucksack: &str,
+    compartment_count: usize,
+) -> Result<usize, String> {
+    alphabet.set(rucksack)?;
+    let item_count = rucksack.chars().count();
+    if !item_count.is_multiple_of(compartment_count) {
+        return Err(format!(
+            "{} items can't be split evenly in {} compartments",
+            item_count, compartment_count
+        ));
+    }
+
+    let sets = compartments(rucksack, compartment_count).map(|c| alphabet.set(c).unwrap());
+    let items = shared(sets);
+    items.single().ok_or_else(|| match items.is_empty() {
+        true => "No item is in every compartment".to_string(),
+        false => format!(
+            "Items {} are all in every compartment",
+            alphabet.list(&items)
+        ),
+    })
+}
+
+// The item every rucksack of the group carries.
+pub fn badge(alphabet: &Alphabet, group: &[&str]) -> Result<usize, String> {
+    let items = shared(group.iter().map(|r| alphabet.set(r).unwrap()));
+    items.single().ok_or_else(|| match items.is_empty() {
+        true => "The group has no badge".to_string(),
+        false => format!("The badge could be any of {}", alphabet.list(&items)),
+    })
+}
+
+#[test]
+fn test_example() {
+    use alphabet::DEFAULT_RANGES;
+
+    let alphabet = Alphabet::from_ranges(DEFAULT_RANGES).unwrap();
+    let rucksacks = include_str!("../test.txt").lines().collect::<Vec<_>>();
+    let part1 = rucksacks
+        .iter()
+        .map(|r| alphabet.priority(misplaced_item(&alphabet, r, 2).unwrap()));
+    assert_eq!(part1.sum::<i64>(), 157);
+    assert_eq!(
+        badge(&alphabet, &rucksacks[..3]).map(|b| alphabet.item(b)),
+        Ok('r')
+    );
+    assert_eq!(
+        badge(&alphabet, &rucksacks[3..]).map(|b| alphabet.item(b)),
+        Ok('Z')
+    );
+
+    assert!(misplaced_item(&alphabet, "abcab", 2).is_err());
+    assert!(misplaced_item(&alphabet, "ab1a", 2).is_err());
+    assert_eq!(
+        badge(&alphabet, &["abc", "bcd"]),
+        Err("The badge could be any of b, c".to_string())
+    );
+}
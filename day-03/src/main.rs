@@ -0,0 +1,130 @@
+use aoc::args::{take_flag, take_option, take_parsed};
+use aoc::input::Input;
+use day_03::alphabet::{Alphabet, DEFAULT_RANGES};
+use day_03::{badge, misplaced_item};
+use std::env;
+use std::error::Error;
+use std::fs;
+
+// A group's badge, with the first and last lines of the group.
+struct Badge {
+    lines: (usize, usize),
+    item: usize,
+}
+
+fn print_badges(alphabet: &Alphabet, badges: &[Badge]) {
+    println!(
+        "{:>5}  {:>11}  {:>5}  {:>8}",
+        "group", "lines", "badge", "priority"
+    );
+    for (n, badge) in badges.iter().enumerate() {
+        let lines = format!("{}-{}", badge.lines.0, badge.lines.1);
+        println!(
+            "{:>5}  {:>11}  {:>5}  {:>8}",
+            n + 1,
+            lines,
+            alphabet.item(badge.item),
+            alphabet.priority(badge.item)
+        );
+    }
+    println!();
+}
+
+// Usage: day-03 [--group-size <n>] [--compartments <n>] [--badges] [--validate]
+//               [--alphabet <ranges> | --priorities <file>] [<input> | - | --example]
+//
+// The items are the letters by default, other alphabets are given either as ranges like
+// "a-z,A-Z,0-9" with the priorities counting up from 1, or as a table of priorities.
+//
+// With --validate, every rucksack and group breaking the rules is reported and the answers only
+// count the valid ones, instead of stopping at the first problem.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let group_size = take_parsed(&mut args, "--group-size")?.unwrap_or(3);
+    let compartment_count = take_parsed(&mut args, "--compartments")?.unwrap_or(2);
+    let show_badges = take_flag(&mut args, "--badges");
+    let validate = take_flag(&mut args, "--validate");
+    let alphabet = match (
+        take_option(&mut args, "--alphabet")?,
+        take_option(&mut args, "--priorities")?,
+    ) {
+        (Some(_), Some(_)) => return Err("Give either --alphabet or --priorities".into()),
+        (Some(ranges), None) => Alphabet::from_ranges(&ranges)?,
+        (None, Some(path)) => {
+            let text =
+                fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path, e))?;
+            Alphabet::from_table(&text).map_err(|e| format!("{}: {}", path, e))?
+        }
+        (None, None) => Alphabet::from_ranges(DEFAULT_RANGES)?,
+    };
+    if group_size == 0 || compartment_count == 0 {
+        return Err("--group-size and --compartments must be at least 1".into());
+    }
+    let input = Input::from_remaining_args(args)?;
+
+    // With their line numbers.
+    let rucksacks = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(n, line)| (n + 1, line))
+        .collect::<Vec<_>>();
+
+    let mut problems = Vec::new();
+    let mut part1 = 0;
+    for &(n, rucksack) in &rucksacks {
+        match misplaced_item(&alphabet, rucksack, compartment_count) {
+            Ok(item) => part1 += alphabet.priority(item),
+            Err(e) => problems.push(format!("Line {}: {}", n, e)),
+        }
+    }
+
+    let mut badges = Vec::new();
+    for group in rucksacks.chunks(group_size) {
+        let lines = (group[0].0, group[group.len() - 1].0);
+        let items = group.iter().map(|(_, r)| *r).collect::<Vec<_>>();
+        if group.len() < group_size {
+            problems.push(format!(
+                "Lines {}-{}: The last group has only {} rucksacks",
+                lines.0,
+                lines.1,
+                group.len()
+            ));
+        } else if items.iter().all(|r| alphabet.set(r).is_ok()) {
+            match badge(&alphabet, &items) {
+                Ok(item) => badges.push(Badge { lines, item }),
+                Err(e) => problems.push(format!("Lines {}-{}: {}", lines.0, lines.1, e)),
+            }
+        }
+        // A group with a rucksack holding something else than items was reported with it.
+    }
+
+    if validate {
+        for problem in &problems {
+            println!("{}", problem);
+        }
+        println!(
+            "{} rucksacks in {} groups, {} problems found",
+            rucksacks.len(),
+            rucksacks.len().div_ceil(group_size),
+            problems.len()
+        );
+    } else if let Some(problem) = problems.first() {
+        return Err(format!("{}, run with --validate to see every problem", problem).into());
+    }
+
+    if show_badges {
+        print_badges(&alphabet, &badges);
+    }
+
+    println!("Part 1: {}", part1);
+    println!(
+        "Part 2: {}",
+        badges
+            .iter()
+            .map(|b| alphabet.priority(b.item))
+            .sum::<i64>()
+    );
+
+    Ok(())
+}
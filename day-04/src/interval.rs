@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+// The sections from `start` to `end`, both included.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Option<Interval> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    // Never 0, `new` doesn't make empty intervals.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    pub fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    // Only when the two leave no gap between them, since the union would be two intervals
+    // otherwise.
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        let touch = self.start <= other.end + 1 && other.start <= self.end + 1;
+        touch.then(|| Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    // What's left of the interval once `other` is taken out of it, nothing, or up to two
+    // intervals when `other` is strictly inside it.
+    pub fn difference(&self, other: &Interval) -> Vec<Interval> {
+        if !self.overlaps(other) {
+            return vec![*self];
+        }
+
+        [
+            Interval::new(self.start, other.start - 1),
+            Interval::new(other.end + 1, self.end),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// The sections from the first start to the last end, cut where the number of intervals covering
+// them changes, with that number.
+pub fn coverage(intervals: &[Interval]) -> Vec<(Interval, usize)> {
+    let mut changes = BTreeMap::new();
+    for interval in intervals {
+        *changes.entry(interval.start).or_insert(0) += 1;
+        *changes.entry(interval.end + 1).or_insert(0) -= 1;
+    }
+
+    let changes = changes.into_iter().collect::<Vec<_>>();
+    let mut count = 0i64;
+    let mut sections = Vec::new();
+    for pair in changes.windows(2) {
+        let [(start, change), (next, _)] = pair else {
+            unreachable!()
+        };
+        count += change;
+        sections.push((Interval::new(*start, next - 1).unwrap(), count as usize));
+    }
+    sections
+}
+
+// Intervals next to each other joined together.
+fn merge(intervals: impl Iterator<Item = Interval>) -> Vec<Interval> {
+    let mut merged: Vec<Interval> = Vec::new();
+    for interval in intervals {
+        match merged.last().and_then(|last| last.union(&interval)) {
+            Some(union) => *merged.last_mut().unwrap() = union,
+            None => merged.push(interval),
+        }
+    }
+    merged
+}
+
+// The sections at least `k` intervals cover.
+pub fn covered_by(intervals: &[Interval], k: usize) -> Vec<Interval> {
+    let sections = coverage(intervals).into_iter();
+    merge(sections.filter(|(_, count)| *count >= k).map(|(s, _)| s))
+}
+
+// The sections no interval covers, between the first start and the last end.
+pub fn uncovered(intervals: &[Interval]) -> Vec<Interval> {
+    let covered = covered_by(intervals, 1);
+    let (Some(first), Some(last)) = (covered.first(), covered.last()) else {
+        return Vec::new();
+    };
+
+    let span = Interval::new(first.start, last.end).unwrap();
+    covered.iter().fold(vec![span], |left, c| {
+        left.iter().flat_map(|l| l.difference(c)).collect()
+    })
+}
+
+// The indices of every two intervals that overlap. The intervals are swept by start, keeping
+// the ones not ended yet, which all overlap the next one.
+pub fn overlapping_pairs(intervals: &[Interval]) -> Vec<(usize, usize)> {
+    let mut order = (0..intervals.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| intervals[i].start);
+
+    let mut active: Vec<usize> = Vec::new();
+    let mut pairs = Vec::new();
+    for i in order {
+        active.retain(|&a| intervals[a].end >= intervals[i].start);
+        pairs.extend(active.iter().map(|&a| (a.min(i), a.max(i))));
+        active.push(i);
+    }
+    pairs.sort();
+    pairs
+}
+
+#[test]
+fn test_interval() {
+    let a = Interval::new(2, 8).unwrap();
+    let b = Interval::new(3, 7).unwrap();
+    let c = Interval::new(9, 12).unwrap();
+    assert!(a.contains(&b) && !b.contains(&a));
+    assert!(a.overlaps(&b) && !a.overlaps(&c));
+    assert_eq!(a.intersection(&b), Some(b));
+    assert_eq!(a.intersection(&c), None);
+    assert_eq!(a.union(&c), Interval::new(2, 12));
+    assert_eq!(b.union(&c), None);
+    assert_eq!(
+        a.difference(&b),
+        [Interval::new(2, 2).unwrap(), Interval::new(8, 8).unwrap()]
+    );
+    assert!(b.difference(&a).is_empty());
+    assert_eq!(Interval::new(3, 2), None);
+}
+
+#[test]
+fn test_coverage() {
+    let intervals = [(2, 4), (6, 8), (3, 7), (11, 12)].map(|(s, e)| Interval::new(s, e).unwrap());
+    assert_eq!(
+        covered_by(&intervals, 2),
+        [Interval::new(3, 4).unwrap(), Interval::new(6, 7).unwrap()]
+    );
+    assert_eq!(
+        covered_by(&intervals, 1),
+        [Interval::new(2, 8).unwrap(), intervals[3]]
+    );
+    assert_eq!(uncovered(&intervals), [Interval::new(9, 10).unwrap()]);
+    assert_eq!(overlapping_pairs(&intervals), [(0, 2), (1, 2)]);
+}
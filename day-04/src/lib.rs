@@ -0,0 +1,16 @@
+use interval::Interval;
+use sscanf::sscanf;
+
+pub mod interval;
+pub mod planner;
+
+pub fn parse_pair(line: &str) -> Result<(Interval, Interval), String> {
+    let expected = || format!("Expected two ranges like 2-4,6-8, found {:?}", line);
+    let (x_min, x_max, y_min, y_max) =
+        sscanf!(line, "{i64}-{i64},{i64}-{i64}").map_err(|_| expected())?;
+
+    match (Interval::new(x_min, x_max), Interval::new(y_min, y_max)) {
+        (Some(x), Some(y)) => Ok((x, y)),
+        _ => Err(format!("A range ends before it starts in {:?}", line)),
+    }
+}
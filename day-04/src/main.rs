@@ -0,0 +1,113 @@
+use aoc::args::{take_flag, take_parsed};
+use aoc::input::Input;
+use day_04::interval::{self, Interval};
+use day_04::{parse_pair, planner};
+use std::env;
+use std::error::Error;
+
+fn print_intervals(title: &str, intervals: &[Interval]) {
+    let sections = intervals.iter().map(|i| i.len()).sum::<i64>();
+    println!("{} ({} sections):", title, sections);
+    for interval in intervals {
+        println!("  {}", interval);
+    }
+    println!();
+}
+
+// Usage: day-04 [--covered <k>] [--uncovered] [--overlaps] [--replan [--budget <sections>]]
+//               [--shifts] [<input> | - | --example]
+//
+// --covered prints the sections at least k elves clean, --uncovered the ones nobody cleans, and
+// --overlaps every two elves whose assignments overlap, pair or not. Elves are named after their
+// line and their place on it, 4.2 being the second elf of line 4.
+//
+// --replan moves the elves apart as little as possible, within the sections up to the budget,
+// by default the last section assigned now. With many elves of different lengths, the plan is
+// the best found rather than the best there is. --shifts gives the number of cleaning shifts needed
+// to keep the assignments as they are.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let covered = take_parsed::<usize>(&mut args, "--covered")?;
+    let show_uncovered = take_flag(&mut args, "--uncovered");
+    let show_overlaps = take_flag(&mut args, "--overlaps");
+    let replan = take_flag(&mut args, "--replan");
+    let budget = take_parsed::<i64>(&mut args, "--budget")?;
+    let show_shifts = take_flag(&mut args, "--shifts");
+    let input = Input::from_remaining_args(args)?;
+
+    // Both answers are counted while parsing, and the assignments are kept for the queries.
+    let mut elves = Vec::new();
+    let (mut contained, mut overlapping) = (0, 0);
+    for (n, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (x, y) = parse_pair(line).map_err(|e| format!("Line {}: {}", n + 1, e))?;
+        if x.contains(&y) || y.contains(&x) {
+            contained += 1;
+        }
+        if x.overlaps(&y) {
+            overlapping += 1;
+        }
+        elves.push((format!("{}.1", n + 1), x));
+        elves.push((format!("{}.2", n + 1), y));
+    }
+
+    let intervals = elves.iter().map(|(_, i)| *i).collect::<Vec<_>>();
+    if let Some(k) = covered {
+        let title = format!("Covered by at least {} elves", k);
+        print_intervals(&title, &interval::covered_by(&intervals, k));
+    }
+    if show_uncovered {
+        print_intervals("Not covered", &interval::uncovered(&intervals));
+    }
+    if show_overlaps {
+        let pairs = interval::overlapping_pairs(&intervals);
+        println!("{} pairs of overlapping elves:", pairs.len());
+        for (a, b) in pairs {
+            let ((name_a, a), (name_b, b)) = (&elves[a], &elves[b]);
+            let shared = a.intersection(b).unwrap();
+            println!(
+                "  {} and {}: {} and {} share {}",
+                name_a, name_b, a, b, shared
+            );
+        }
+        println!();
+    }
+
+    if replan {
+        let budget = budget.unwrap_or(intervals.iter().map(|i| i.end).max().unwrap_or(0));
+        let plan = planner::replan(&intervals, budget)?;
+        let moves = elves.iter().zip(&plan.intervals);
+        let moves = moves
+            .filter(|((_, old), new)| old != *new)
+            .collect::<Vec<_>>();
+        println!(
+            "Moving {} elves by {} sections in total, within {} sections{}:",
+            moves.len(),
+            plan.shift,
+            budget,
+            if plan.exact {
+                ""
+            } else {
+                " (the best found, maybe not the least)"
+            }
+        );
+        for ((name, old), new) in moves {
+            println!("  {}: {} -> {}", name, old, new);
+        }
+        println!();
+    }
+    if show_shifts {
+        let shifts = planner::shifts(&intervals);
+        let count = shifts.iter().max().map_or(0, |s| s + 1);
+        println!("Cleaning shifts needed to keep the overlaps: {}", count);
+        println!();
+    }
+
+    println!("Part 1: {}", contained);
+    println!("Part 2: {}", overlapping);
+
+    Ok(())
+}
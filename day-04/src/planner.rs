@@ -0,0 +1,279 @@
+use crate::interval::Interval;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+// New sections for every elf, in the order the elves were given, how far their starts moved in
+// total, and whether no plan moves them less.
+pub struct Plan {
+    pub intervals: Vec<Interval>,
+    pub shift: i64,
+    pub exact: bool,
+}
+
+// Past this many states, elves aren't placed in every order but improved from a good one.
+const EXACT_STATES: usize = 1 << 22;
+
+// The non-decreasing sequence closest to `targets`, in total absolute difference. The heap holds
+// the best values so far, its top being where the last element goes (the "slope trick").
+fn fit_non_decreasing(targets: &[i64]) -> Vec<i64> {
+    let mut heap = BinaryHeap::new();
+    let mut fitted = Vec::with_capacity(targets.len());
+    for &target in targets {
+        heap.push(target);
+        if *heap.peek().unwrap() > target {
+            heap.pop();
+            heap.push(target);
+        }
+        fitted.push(*heap.peek().unwrap());
+    }
+
+    for i in (1..fitted.len()).rev() {
+        fitted[i - 1] = fitted[i - 1].min(fitted[i]);
+    }
+    fitted
+}
+
+// The best starts for the elves placed one after the other in `order`. Taking away the sections
+// of the elves before, the starts only have to go up along the order, and stay between 1 and
+// what's left of the budget after all the elves.
+fn fit_order(intervals: &[Interval], order: &[usize], budget: i64) -> (Vec<i64>, i64) {
+    let needed = intervals.iter().map(|i| i.len()).sum::<i64>();
+    let mut before = 0;
+    let mut targets = Vec::with_capacity(order.len());
+    for &i in order {
+        targets.push(intervals[i].start - before);
+        before += intervals[i].len();
+    }
+
+    let mut starts = vec![0; intervals.len()];
+    let mut before = 0;
+    for (&i, start) in order.iter().zip(fit_non_decreasing(&targets)) {
+        starts[i] = start.clamp(1, budget - needed + 1) + before;
+        before += intervals[i].len();
+    }
+    let shift = intervals
+        .iter()
+        .zip(&starts)
+        .map(|(old, new)| (new - old.start).abs())
+        .sum();
+    (starts, shift)
+}
+
+// The best starts over every order of the elves: the least shift placing the elves of a subset
+// within the first sections, the last section being left free or ending one of the elves.
+fn exact_starts(intervals: &[Interval], budget: i64) -> Vec<i64> {
+    let (n, width) = (intervals.len(), budget as usize + 1);
+    let mut shift = vec![i64::MAX; (1 << n) * width];
+    shift[..width].fill(0);
+    for set in 1..1usize << n {
+        for end in 1..width {
+            let mut best = shift[set * width + end - 1];
+            for i in (0..n).filter(|i| set & 1 << i != 0) {
+                let len = intervals[i].len() as usize;
+                if len > end {
+                    continue;
+                }
+                let before = shift[(set ^ 1 << i) * width + end - len];
+                if before != i64::MAX {
+                    let start = (end - len + 1) as i64;
+                    best = best.min(before + (start - intervals[i].start).abs());
+                }
+            }
+            shift[set * width + end] = best;
+        }
+    }
+
+    // Going back from the whole set, each section either stays free or ends the elf that gave
+    // the best shift.
+    let mut starts = vec![0; n];
+    let (mut set, mut end) = ((1 << n) - 1, width - 1);
+    while set != 0 {
+        let here = shift[set * width + end];
+        if shift[set * width + end - 1] == here {
+            end -= 1;
+            continue;
+        }
+        let i = (0..n)
+            .filter(|i| set & 1 << i != 0 && intervals[*i].len() as usize <= end)
+            .find(|&i| {
+                let len = intervals[i].len() as usize;
+                let before = shift[(set ^ 1 << i) * width + end - len];
+                let start = (end - len + 1) as i64;
+                before != i64::MAX && before + (start - intervals[i].start).abs() == here
+            })
+            .unwrap();
+        starts[i] = (end - intervals[i].len() as usize + 1) as i64;
+        set ^= 1 << i;
+        end -= intervals[i].len() as usize;
+    }
+    starts
+}
+
+// The best starts found from the start and middle orders of the elves, swapping neighbours of
+// different lengths while that helps. Elves of the same length are best kept in start order.
+fn improved_starts(intervals: &[Interval], budget: i64) -> Vec<i64> {
+    let mut best = None;
+    for key in [|i: &Interval| 2 * i.start, |i: &Interval| i.start + i.end] {
+        let mut order = (0..intervals.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| (key(&intervals[i]), intervals[i].start));
+        let (mut starts, mut shift) = fit_order(intervals, &order, budget);
+
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for k in 1..order.len() {
+                if intervals[order[k - 1]].len() == intervals[order[k]].len() {
+                    continue;
+                }
+                order.swap(k - 1, k);
+                let (swapped, swapped_shift) = fit_order(intervals, &order, budget);
+                if swapped_shift < shift {
+                    (starts, shift) = (swapped, swapped_shift);
+                    improved = true;
+                } else {
+                    order.swap(k - 1, k);
+                }
+            }
+        }
+
+        if best.as_ref().is_none_or(|(_, best)| shift < *best) {
+            best = Some((starts, shift));
+        }
+    }
+    best.unwrap().0
+}
+
+// Gives every elf as many sections as before within sections 1 to `budget`, no two elves sharing
+// a section, moving the starts as little as possible in total. With few elves, every order is
+// tried. Otherwise the plan is the best found from the orders of the starts and the middles.
+// Elves that all have as many sections keep the order of their starts, which is then always best.
+pub fn replan(intervals: &[Interval], budget: i64) -> Result<Plan, String> {
+    let needed = intervals.iter().map(|i| i.len()).sum::<i64>();
+    if needed > budget {
+        return Err(format!(
+            "No plan fits: the elves need {} sections, {} more than the budget of {}",
+            needed,
+            needed - budget,
+            budget
+        ));
+    }
+
+    let same_length = intervals.windows(2).all(|w| w[0].len() == w[1].len());
+    let states = 1usize
+        .checked_shl(intervals.len() as u32)
+        .and_then(|sets| sets.checked_mul(budget as usize + 1));
+    let (starts, exact) = if same_length {
+        let mut order = (0..intervals.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| (intervals[i].start, intervals[i].end));
+        (fit_order(intervals, &order, budget).0, true)
+    } else if states.is_some_and(|states| states <= EXACT_STATES) {
+        (exact_starts(intervals, budget), true)
+    } else {
+        (improved_starts(intervals, budget), false)
+    };
+
+    let planned = intervals
+        .iter()
+        .zip(&starts)
+        .map(|(old, &start)| Interval::new(start, start + old.len() - 1).unwrap())
+        .collect::<Vec<_>>();
+    let shift = intervals
+        .iter()
+        .zip(&planned)
+        .map(|(old, new)| (new.start - old.start).abs())
+        .sum();
+    Ok(Plan {
+        intervals: planned,
+        shift,
+        exact,
+    })
+}
+
+// The cleaning shift of each elf, when elves with overlapping sections can't clean at the same
+// time. Taking the elves by start and giving each the first free shift needs as many shifts as
+// there are elves on the busiest section, which is the fewest possible.
+pub fn shifts(intervals: &[Interval]) -> Vec<usize> {
+    let mut order = (0..intervals.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&i| intervals[i].start);
+
+    let mut shifts = vec![0; intervals.len()];
+    let mut free = BinaryHeap::new();
+    let mut busy = BinaryHeap::new();
+    let mut shift_count = 0;
+    for i in order {
+        while let Some(&Reverse((end, shift))) = busy.peek() {
+            if end >= intervals[i].start {
+                break;
+            }
+            busy.pop();
+            free.push(Reverse(shift));
+        }
+
+        let shift = match free.pop() {
+            Some(Reverse(shift)) => shift,
+            None => {
+                shift_count += 1;
+                shift_count - 1
+            }
+        };
+        shifts[i] = shift;
+        busy.push(Reverse((intervals[i].end, shift)));
+    }
+    shifts
+}
+
+#[test]
+fn test_replan() {
+    let intervals =
+        [(2, 4), (6, 8), (2, 3), (4, 5), (5, 7), (7, 9)].map(|(s, e)| Interval::new(s, e).unwrap());
+    assert!(replan(&intervals, 15).is_err());
+
+    let plan = replan(&intervals, 16).unwrap();
+    let mut sorted = plan.intervals.clone();
+    sorted.sort_by_key(|i| i.start);
+    assert!(sorted.windows(2).all(|w| w[0].end < w[1].start));
+    assert!(sorted[0].start >= 1 && sorted[5].end <= 16);
+    for (old, new) in intervals.iter().zip(&plan.intervals) {
+        assert_eq!(old.len(), new.len());
+    }
+
+    // Against every plan, for elves with two sections each, then with different lengths.
+    let least_shift = |intervals: &[Interval], budget: i64| {
+        let n = intervals.len() as u32;
+        let mut best = i64::MAX;
+        for starts in 0..budget.pow(n) {
+            let starts = (0..n).map(|k| starts / budget.pow(k) % budget + 1);
+            let planned = intervals
+                .iter()
+                .zip(starts)
+                .map(|(i, start)| Interval::new(start, start + i.len() - 1).unwrap())
+                .collect::<Vec<_>>();
+            let fits = planned.iter().all(|i| i.end <= budget);
+            let disjoint =
+                (0..planned.len()).all(|a| (0..a).all(|b| !planned[a].overlaps(&planned[b])));
+            if fits && disjoint {
+                let shift = intervals.iter().zip(&planned);
+                best = best.min(shift.map(|(old, new)| (new.start - old.start).abs()).sum());
+            }
+        }
+        best
+    };
+    let intervals = [(3, 4), (2, 3), (6, 7), (6, 7)].map(|(s, e)| Interval::new(s, e).unwrap());
+    assert_eq!(
+        replan(&intervals, 9).unwrap().shift,
+        least_shift(&intervals, 9)
+    );
+
+    let unequal = [(1, 10), (2, 2)].map(|(s, e)| Interval::new(s, e).unwrap());
+    assert_eq!(replan(&unequal, 11).unwrap().shift, 2);
+    let unequal = [(2, 5), (1, 1), (3, 3), (4, 6)].map(|(s, e)| Interval::new(s, e).unwrap());
+    for budget in 9..12 {
+        let plan = replan(&unequal, budget).unwrap();
+        assert!(plan.exact);
+        assert_eq!(plan.shift, least_shift(&unequal, budget));
+    }
+
+    let shifts = shifts(&intervals);
+    assert_eq!(shifts.iter().max(), Some(&1));
+    assert_ne!(shifts[2], shifts[3]);
+}
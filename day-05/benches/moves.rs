@@ -0,0 +1,81 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_05::crane::{parse_crane, Stacks};
+use day_05::moves;
+use sscanf::sscanf;
+
+// Valid moves between 9 stacks of 1000 crates, from a fixed seed, with large amounts.
+fn stress_input(count: usize) -> (Stacks, String) {
+    let stacks = (0..9)
+        .map(|n| vec![(b'A' + n) as char; 1000])
+        .collect::<Stacks>();
+    let mut heights = [1000; 9];
+    let mut seed = 0x2545f4914f6cdd1du64;
+    let mut random = |n: usize| {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed as usize % n
+    };
+
+    let mut text = String::new();
+    for _ in 0..count {
+        let mut from = random(9);
+        while heights[from] == 0 {
+            from = (from + 1) % 9;
+        }
+        let to = (from + 1 + random(8)) % 9;
+        let amount = 1 + random(heights[from].min(200));
+        heights[from] -= amount;
+        heights[to] += amount;
+        text.push_str(&format!(
+            "move {} from {} to {}\n",
+            amount,
+            from + 1,
+            to + 1
+        ));
+    }
+    (stacks, text)
+}
+
+// The loop the moves ran in before: sscanf, then one crane after the other, one crate at a time
+// for the 9000.
+fn old_loop(stacks: &Stacks, text: &str) -> Vec<Stacks> {
+    let mut crane_stacks = vec![stacks.clone(); 2];
+    for line in text.lines() {
+        let (amount, from, to) = sscanf!(line, "move {usize} from {usize} to {usize}").unwrap();
+        let (from, to) = (from - 1, to - 1);
+
+        let stacks = &mut crane_stacks[0];
+        for _ in 0..amount {
+            let item = stacks[from].pop().unwrap();
+            stacks[to].push(item);
+        }
+
+        let stacks = &mut crane_stacks[1];
+        let height = stacks[from].len();
+        let mut crates = stacks[from].split_off(height - amount);
+        stacks[to].append(&mut crates);
+    }
+    crane_stacks
+}
+
+fn bulk(stacks: &Stacks, text: &str) -> Vec<Stacks> {
+    let (checked, _) = moves::check_moves(stacks, text.as_bytes().split(|b| *b == b'\n'));
+    let checked = checked.into_iter().map(|(_, m)| m).collect::<Vec<_>>();
+    let mut cranes = vec![parse_crane("9000").unwrap(), parse_crane("9001").unwrap()];
+    moves::run_parallel(stacks, &checked, &mut cranes)
+}
+
+fn bench_moves(c: &mut Criterion) {
+    let (stacks, text) = stress_input(200_000);
+    assert!(old_loop(&stacks, &text) == bulk(&stacks, &text));
+
+    let mut group = c.benchmark_group("200000 moves");
+    group.sample_size(10);
+    group.bench_function("old loop", |b| b.iter(|| old_loop(&stacks, &text)));
+    group.bench_function("bulk", |b| b.iter(|| bulk(&stacks, &text)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_moves);
+criterion_main!(benches);
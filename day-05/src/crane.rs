@@ -0,0 +1,189 @@
+// The stacks, bottom crate first.
+pub type Stacks = Vec<Vec<char>>;
+
+// A move as written in the input: amount, then stacks numbered from 1.
+pub type Move = (usize, usize, usize);
+
+// A crane model, moving `amount` crates from the top of stack `from` to the top of stack `to`.
+// Stacks are numbered from 0 here. Cranes may keep state from one move to the next.
+pub trait Crane {
+    fn name(&self) -> String;
+    fn apply(&mut self, stacks: &mut Stacks, amount: usize, from: usize, to: usize);
+
+    // Whether a move depends on the moves before, which the solver can't deal with.
+    fn keeps_state(&self) -> bool {
+        false
+    }
+}
+
+// Moves `amount` crates at once, keeping their order. Crates put back on the stack they come from
+// don't move at all, so none are given back.
+fn lift(stacks: &mut Stacks, amount: usize, from: usize, to: usize) -> &mut [char] {
+    if from == to {
+        return &mut [];
+    }
+    let from_stack = &mut stacks[from];
+    let mut crates = from_stack.split_off(from_stack.len() - amount);
+    let to_stack = &mut stacks[to];
+    to_stack.append(&mut crates);
+    let len = to_stack.len();
+    &mut to_stack[len - amount..]
+}
+
+// One crate at a time, which reverses their order. They're moved all at once and then turned
+// over, which ends the same.
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(&self) -> String {
+        "CrateMover 9000".to_string()
+    }
+
+    fn apply(&mut self, stacks: &mut Stacks, amount: usize, from: usize, to: usize) {
+        lift(stacks, amount, from, to).reverse();
+    }
+}
+
+// All the crates at once.
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(&self) -> String {
+        "CrateMover 9001".to_string()
+    }
+
+    fn apply(&mut self, stacks: &mut Stacks, amount: usize, from: usize, to: usize) {
+        lift(stacks, amount, from, to);
+    }
+}
+
+// Lifts at most `capacity` crates at once, so larger moves take several lifts from the top.
+pub struct LimitedCrane {
+    pub capacity: usize,
+}
+
+impl Crane for LimitedCrane {
+    fn name(&self) -> String {
+        format!("Crane lifting up to {} crates", self.capacity)
+    }
+
+    fn apply(&mut self, stacks: &mut Stacks, amount: usize, from: usize, to: usize) {
+        let mut left = amount;
+        while left > 0 {
+            let lifted = left.min(self.capacity);
+            lift(stacks, lifted, from, to);
+            left -= lifted;
+        }
+    }
+}
+
+// Lifts all the crates at once, but turns every second batch upside down.
+#[derive(Default)]
+pub struct AlternatingCrane {
+    reverse: bool,
+}
+
+impl Crane for AlternatingCrane {
+    fn name(&self) -> String {
+        "Alternating crane".to_string()
+    }
+
+    fn apply(&mut self, stacks: &mut Stacks, amount: usize, from: usize, to: usize) {
+        // A batch put back onto its own stack still counts.
+        let reverse = self.reverse;
+        self.reverse = !reverse;
+        if from == to {
+            return;
+        }
+        let crates = lift(stacks, amount, from, to);
+        if reverse {
+            crates.reverse();
+        }
+    }
+
+    fn keeps_state(&self) -> bool {
+        true
+    }
+}
+
+// Checks a move before any crane makes it, with the stacks numbered from 1 as in the input.
+// Every crane leaves the stacks with the same heights, so one check holds for all of them.
+pub fn check_move(heights: &[usize], amount: usize, from: usize, to: usize) -> Result<(), String> {
+    for stack in [from, to] {
+        if stack == 0 || stack > heights.len() {
+            return Err(format!(
+                "There is no stack {}, the stacks go from 1 to {}",
+                stack,
+                heights.len()
+            ));
+        }
+    }
+
+    let height = heights[from - 1];
+    if amount > height {
+        return Err(format!("Stack {} only holds {} crates", from, height));
+    }
+    Ok(())
+}
+
+// A crane from its name on the command line: 9000, 9001, capacity=<n> or alternating.
+pub fn parse_crane(spec: &str) -> Result<Box<dyn Crane + Send>, String> {
+    match spec.split_once('=') {
+        None if spec == "9000" => Ok(Box::new(CrateMover9000)),
+        None if spec == "9001" => Ok(Box::new(CrateMover9001)),
+        None if spec == "alternating" => Ok(Box::<AlternatingCrane>::default()),
+        Some(("capacity", capacity)) => match capacity.parse() {
+            Ok(capacity) if capacity > 0 => Ok(Box::new(LimitedCrane { capacity })),
+            _ => Err(format!("Invalid capacity {:?}", capacity)),
+        },
+        _ => Err(format!(
+            "Unknown crane {}, expected 9000, 9001, capacity=<n> or alternating",
+            spec
+        )),
+    }
+}
+
+#[test]
+fn test_cranes() {
+    let moves = [(1, 1, 0), (3, 0, 2), (2, 1, 0), (1, 0, 1)];
+    let tops = |spec: &str| {
+        let mut crane = parse_crane(spec).unwrap();
+        let mut stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        for (amount, from, to) in moves {
+            crane.apply(&mut stacks, amount, from, to);
+        }
+        stacks
+            .iter()
+            .map(|s| *s.last().unwrap())
+            .collect::<String>()
+    };
+
+    assert_eq!(tops("9000"), "CMZ");
+    assert_eq!(tops("9001"), "MCD");
+    assert_eq!(tops("capacity=1"), "CMZ");
+    assert_eq!(tops("capacity=2"), "MCZ");
+    assert_eq!(tops("capacity=3"), "MCD");
+    assert_eq!(tops("alternating"), "MCZ");
+    assert!(parse_crane("capacity=0").is_err());
+
+    // Moving crates onto their own stack leaves it as it was.
+    for spec in ["9000", "9001", "capacity=1", "alternating"] {
+        let mut crane = parse_crane(spec).unwrap();
+        let mut stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+        crane.apply(&mut stacks, 2, 1, 1);
+        assert_eq!(stacks[1], ['M', 'C', 'D']);
+    }
+
+    // But it is a batch for the alternating crane, so the next one is turned upside down.
+    let mut crane = AlternatingCrane::default();
+    let mut stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+    crane.apply(&mut stacks, 2, 1, 1);
+    crane.apply(&mut stacks, 2, 1, 0);
+    assert_eq!(stacks[0], ['Z', 'N', 'D', 'C']);
+
+    let heights = [2, 0];
+    assert!(check_move(&heights, 2, 1, 2).is_ok());
+    assert!(check_move(&heights, 3, 1, 2).is_err());
+    assert!(check_move(&heights, 1, 0, 2).is_err());
+    assert!(check_move(&heights, 1, 1, 3).is_err());
+}
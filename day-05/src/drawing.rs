@@ -0,0 +1,137 @@
+use crate::crane::Stacks;
+
+// The numbers of the footer, with the first and last columns they span from 0.
+fn labels(footer: &str) -> Result<Vec<(usize, usize, usize)>, String> {
+    let mut labels = Vec::new();
+    let mut start = None;
+    for (i, c) in footer.char_indices().chain([(footer.len(), ' ')]) {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(first)) => {
+                let label = footer[first..i]
+                    .parse()
+                    .map_err(|_| format!("Stack number {} is too large", &footer[first..i]))?;
+                labels.push((first, i - 1, label));
+                start = None;
+            }
+            (false, None) if c != ' ' => {
+                return Err(format!("Unexpected {:?} in column {}", c, i + 1));
+            }
+            _ => (),
+        }
+    }
+
+    for (n, (_, _, label)) in labels.iter().enumerate() {
+        if *label != n + 1 {
+            return Err(format!("Expected stack {}, found {}", n + 1, label));
+        }
+    }
+    if labels.is_empty() {
+        return Err("The footer has no stack numbers".to_string());
+    }
+    Ok(labels)
+}
+
+// The stacks from the drawing at the top of the input, footer included:
+//
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+//
+// Each crate goes to the stack whose number is under its item, give or take a column, so lines
+// may be trimmed and numbers may take several digits.
+pub fn parse(drawing: &[&str]) -> Result<Stacks, String> {
+    let Some((footer, rows)) = drawing.split_last() else {
+        return Err("The drawing is empty".to_string());
+    };
+    let labels = labels(footer).map_err(|e| format!("Line {}: {}", drawing.len(), e))?;
+
+    let mut stacks: Stacks = vec![Vec::new(); labels.len()];
+    for (n, row) in rows.iter().enumerate().rev() {
+        let error = |column: usize, e: &str| format!("Line {}, column {}: {}", n + 1, column, e);
+        let chars = row.chars().collect::<Vec<_>>();
+
+        let mut column = 0;
+        while column < chars.len() {
+            match chars[column..] {
+                [' ', ..] => column += 1,
+                ['[', item, ']', ..] => {
+                    let item_column = column + 1;
+                    let stack = labels
+                        .iter()
+                        .position(|(first, last, _)| {
+                            first.saturating_sub(1) <= item_column && item_column <= last + 1
+                        })
+                        .ok_or_else(|| error(item_column + 1, "The crate is under no stack"))?;
+                    if stacks[stack].len() != rows.len() - 1 - n {
+                        return Err(error(item_column + 1, "The crate floats in the air"));
+                    }
+
+                    stacks[stack].push(item);
+                    column += 3;
+                }
+                _ => {
+                    let unexpected = format!("Unexpected {:?}", chars[column]);
+                    return Err(error(column + 1, &unexpected));
+                }
+            }
+        }
+    }
+
+    Ok(stacks)
+}
+
+// The drawing of the stacks, in the format of the input. Rows are padded to the width of all the
+// stacks, as in the puzzle input.
+pub fn render(stacks: &Stacks) -> String {
+    let height = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<_>>();
+        out.push_str(&row.join(" "));
+        out.push('\n');
+    }
+
+    // Labels start above the crates, so that two-digit ones still fit.
+    let footer = (1..=stacks.len())
+        .map(|n| format!(" {:<2}", n))
+        .collect::<Vec<_>>();
+    out.push_str(&footer.join(" "));
+    out.push('\n');
+
+    out
+}
+
+#[test]
+fn test_round_trip() {
+    let text = include_str!("../test.txt");
+    let drawing = text
+        .lines()
+        .take_while(|l| !l.is_empty())
+        .collect::<Vec<_>>();
+    let stacks = parse(&drawing).unwrap();
+    assert_eq!(stacks, [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+    assert_eq!(render(&stacks), drawing.join("\n") + "\n");
+
+    let trimmed = drawing.iter().map(|l| l.trim_end()).collect::<Vec<_>>();
+    assert_eq!(parse(&trimmed).unwrap(), stacks);
+
+    let wide = (0..12)
+        .map(|n| vec![(b'A' + n) as char; n as usize % 3])
+        .collect();
+    let drawing = render(&wide);
+    assert!(drawing.ends_with(" 10  11  12\n"));
+    assert_eq!(parse(&drawing.lines().collect::<Vec<_>>()).unwrap(), wide);
+
+    assert!(parse(&["[A]", "    [B]", " 1   2"]).is_err());
+    assert!(parse(&["[A]", " 1   3"]).is_err());
+    assert!(parse(&["[A]", " 99999999999999999999999"]).is_err());
+}
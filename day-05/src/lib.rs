@@ -0,0 +1,4 @@
+pub mod crane;
+pub mod drawing;
+pub mod moves;
+pub mod solver;
@@ -1,70 +1,185 @@
-use sscanf::sscanf;
+use aoc::args::{take_flag, take_option, take_parsed};
+use aoc::cast::Recorder;
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
+use day_05::crane::{parse_crane, Crane, Stacks};
+use day_05::{drawing, moves, solver};
+use std::env;
 use std::error::Error;
-use std::io;
+use std::fs;
+use std::thread;
+use std::time::Duration;
 
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
+fn stacks_to_strings(stacks: &[Vec<char>]) -> Vec<String> {
+    stacks.iter().map(|stack| stack.iter().collect()).collect()
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut stack_lines: Vec<String> = Vec::new();
-    let mut lines = stdin_lines();
-
-    loop {
-        let line = lines.next().unwrap();
+// The drawing of every crane's stacks, under the line that led to them.
+fn frame(line: &str, cranes: &[Box<dyn Crane + Send>], crane_stacks: &[Stacks]) -> String {
+    let mut out = format!("{}\n", line);
+    for (crane, stacks) in cranes.iter().zip(crane_stacks) {
+        out.push_str(&format!("\n{}:\n{}", crane.name(), drawing::render(stacks)));
+    }
+    out
+}
 
-        if line.chars().nth(1).unwrap() == '1' {
-            break;
-        }
+// The drawing at the top of the lines, and the index of the empty line after it.
+fn split_drawing(lines: &[&str]) -> Result<(Stacks, usize), String> {
+    let blank = lines
+        .iter()
+        .position(|line| line.trim().is_empty())
+        .unwrap_or(lines.len());
+    Ok((drawing::parse(&lines[..blank])?, blank))
+}
 
-        stack_lines.push(line);
+// Prints an input going from the drawing to the target drawing in as few moves as possible.
+fn solve(
+    stacks: &Stacks,
+    target_path: &str,
+    crane: &mut dyn Crane,
+    max_moves: usize,
+) -> Result<(), Box<dyn Error>> {
+    let text = fs::read_to_string(target_path)
+        .map_err(|e| format!("Cannot read {}: {}", target_path, e))?;
+    let (target, _) = split_drawing(&text.lines().collect::<Vec<_>>())
+        .map_err(|e| format!("{}: {}", target_path, e))?;
+    if crane.keeps_state() {
+        return Err(format!("The solver can't plan for the {}", crane.name()).into());
     }
 
-    let num_stacks = (stack_lines[0].len() + 1) / 4;
-    let mut stacks: Vec<Vec<char>> = Vec::with_capacity(num_stacks);
-    for _ in 0..num_stacks {
-        stacks.push(Vec::new())
+    let moves = solver::solve(stacks, &target, crane, max_moves)?;
+    print!("{}", drawing::render(stacks));
+    println!();
+    for (amount, from, to) in &moves {
+        println!("move {} from {} to {}", amount, from, to);
     }
+    eprintln!("{} moves with the {}", moves.len(), crane.name());
 
-    for stack_line in stack_lines.iter().rev() {
-        for (n, char) in stack_line.chars().skip(1).step_by(4).enumerate() {
-            if char != ' ' {
-                stacks[n].push(char);
-            }
+    Ok(())
+}
+
+// Usage: day-05 [--cranes <crane>,...] [--steps | --animate [--delay <ms>]] [--lenient]
+//               [<input> | - | --example]
+//        day-05 --solve <target> [--cranes <crane>] [--max-moves <n>] [<input> | - | --example]
+//
+// Every crane runs the same moves from the same drawing, the cranes being 9000 and 9001 by
+// default. See `parse_crane` for the other models. --steps prints the drawings after every move,
+// and --animate shows them in place, waiting 300ms between moves by default. Moves that can't be
+// made stop the cranes, or are skipped with --lenient. Empty stacks show as a space on top.
+//
+// --solve ignores the moves, and prints an input going from the drawing to the target's drawing
+// in as few moves as possible, 12 at most by default, with one crane, the 9000 by default.
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let target = take_option(&mut args, "--solve")?;
+    let max_moves = take_parsed(&mut args, "--max-moves")?.unwrap_or(12);
+    let default_cranes = if target.is_some() {
+        "9000"
+    } else {
+        "9000,9001"
+    };
+    let cranes = take_option(&mut args, "--cranes")?.unwrap_or(default_cranes.to_string());
+    let mut cranes = cranes
+        .split(',')
+        .map(|spec| parse_crane(spec.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let show_steps = take_flag(&mut args, "--steps");
+    let animate = take_flag(&mut args, "--animate");
+    let delay = Duration::from_millis(take_parsed(&mut args, "--delay")?.unwrap_or(300));
+    let lenient = take_flag(&mut args, "--lenient");
+    let input = Input::from_remaining_args(args)?;
+    let mut tracer = Tracer::from_env(5);
+    let mut recorder = Recorder::from_env();
+    let showing = show_steps || animate || recorder.is_enabled();
+    let mut show = |frame: &str| {
+        if show_steps {
+            println!("{}", frame);
+        }
+        if animate {
+            print!("\x1b[2J\x1b[H{}", frame);
+            thread::sleep(delay);
         }
+        recorder.frame(frame);
+    };
+    let lines = input.lines().collect::<Vec<_>>();
+    let (stacks, blank) = split_drawing(&lines)?;
+    if let Some(target) = target {
+        let [crane] = &mut cranes[..] else {
+            return Err("Give a single crane to --solve".into());
+        };
+        return solve(&stacks, &target, crane.as_mut(), max_moves);
     }
 
-    // Skip the empty line;
-    lines.next();
-
-    let mut stacks2 = stacks.clone();
+    tracer.record("start", || {
+        json!({
+            "stacks": stacks_to_strings(&stacks),
+            "drawing": drawing::render(&stacks),
+        })
+    });
+    let mut crane_stacks = vec![stacks; cranes.len()];
+    if showing {
+        show(&frame("Start", &cranes, &crane_stacks));
+    }
 
-    for line in lines {
-        let (amount, from, to) = sscanf!(line, "move {usize} from {usize} to {usize}").unwrap();
+    // The drawing, its footer and the empty line come before the first move.
+    let first_move_line = blank + 2;
+    let move_lines = input.byte_lines().skip(blank + 1).collect::<Vec<_>>();
 
-        // Part 1.
-        for _ in 0..amount {
-            let item = stacks[from - 1].pop().unwrap();
-            stacks[to - 1].push(item);
+    // All the moves are checked first, so that the cranes don't have to.
+    let (checked, bad) = moves::check_moves(&crane_stacks[0], move_lines.iter().copied());
+    for bad_move in &bad {
+        let heights = bad_move.heights.iter().map(|h| h.to_string());
+        let e = format!(
+            "Line {}: {}: {} (stack heights {})",
+            first_move_line + bad_move.index,
+            String::from_utf8_lossy(move_lines[bad_move.index]),
+            bad_move.error,
+            heights.collect::<Vec<_>>().join(", ")
+        );
+        if !lenient {
+            return Err(format!("{}, run with --lenient to skip bad moves", e).into());
         }
-
-        // Part 2.
-        let from_stack = &mut stacks2[from - 1];
-        let mut items = from_stack.split_off(from_stack.len() - amount);
-        stacks2[to - 1].append(&mut items);
+        eprintln!("Skipping {}", e);
     }
 
-    let part1: String = stacks.iter().fold(String::new(), |mut acc, value| {
-        acc.push(*value.last().unwrap());
-        acc
-    });
-    let part2: String = stacks2.iter().fold(String::new(), |mut acc, value| {
-        acc.push(*value.last().unwrap());
-        acc
-    });
+    if showing || tracer.is_enabled() {
+        for &(n, (amount, from, to)) in &checked {
+            for (crane, stacks) in cranes.iter_mut().zip(&mut crane_stacks) {
+                crane.apply(stacks, amount, from - 1, to - 1);
+                tracer.record("move", || {
+                    json!({
+                        "line": first_move_line + n,
+                        "crane": crane.name(),
+                        "amount": amount,
+                        "from": from,
+                        "to": to,
+                        "stacks": stacks_to_strings(stacks),
+                        "drawing": drawing::render(stacks),
+                    })
+                });
+            }
+            if showing {
+                let line = String::from_utf8_lossy(move_lines[n]);
+                show(&frame(&line, &cranes, &crane_stacks));
+            }
+        }
+    } else {
+        // Nothing to show between moves, so every crane runs through all of them on its own.
+        let moves = checked.iter().map(|(_, m)| *m).collect::<Vec<_>>();
+        crane_stacks = moves::run_parallel(&crane_stacks[0], &moves, &mut cranes);
+    }
 
-    println!("part1: {part1}");
-    println!("part2: {part2}");
+    let skipped = bad.len();
+    if skipped > 0 {
+        eprintln!("Skipped {} bad moves", skipped);
+    }
+    for (crane, stacks) in cranes.iter().zip(&crane_stacks) {
+        let tops = stacks
+            .iter()
+            .map(|s| s.last().copied().unwrap_or(' '))
+            .collect::<String>();
+        println!("{}: {}", crane.name(), tops);
+    }
 
     Ok(())
 }
@@ -0,0 +1,123 @@
+use crate::crane::{check_move, Crane, Move, Stacks};
+use std::thread;
+
+// The number at the start of `bytes`, and what follows it.
+fn number(bytes: &[u8]) -> Option<(usize, &[u8])> {
+    let digits = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+    if digits == 0 || digits > 18 {
+        return None;
+    }
+    let value = bytes[..digits]
+        .iter()
+        .fold(0, |value, b| value * 10 + usize::from(b - b'0'));
+    Some((value, &bytes[digits..]))
+}
+
+// A move like `move 1 from 2 to 1`, read straight from the bytes of its line.
+pub fn parse_move(line: &[u8]) -> Option<Move> {
+    let (amount, rest) = number(line.strip_prefix(b"move ")?)?;
+    let (from, rest) = number(rest.strip_prefix(b" from ")?)?;
+    let (to, rest) = number(rest.strip_prefix(b" to ")?)?;
+    rest.is_empty().then_some((amount, from, to))
+}
+
+// A move that can't be made, with the stack heights at that point.
+pub struct BadMove {
+    pub index: usize,
+    pub error: String,
+    pub heights: Vec<usize>,
+}
+
+// Checks every move against the stack heights alone, before any crane runs. Bad moves are left
+// out and given back on the side, so the cranes can then run the good ones without checking.
+pub fn check_moves<'a>(
+    stacks: &Stacks,
+    lines: impl Iterator<Item = &'a [u8]>,
+) -> (Vec<(usize, Move)>, Vec<BadMove>) {
+    let mut heights = stacks.iter().map(|s| s.len()).collect::<Vec<_>>();
+    let (mut moves, mut bad) = (Vec::new(), Vec::new());
+    for (index, line) in lines.enumerate() {
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+
+        let checked = parse_move(line)
+            .ok_or_else(|| "Expected a move like `move 1 from 2 to 1`".to_string())
+            .and_then(|(amount, from, to)| {
+                check_move(&heights, amount, from, to).map(|_| (amount, from, to))
+            });
+        match checked {
+            Ok((amount, from, to)) => {
+                heights[from - 1] -= amount;
+                heights[to - 1] += amount;
+                moves.push((index, (amount, from, to)));
+            }
+            Err(error) => bad.push(BadMove {
+                index,
+                error,
+                heights: heights.clone(),
+            }),
+        }
+    }
+    (moves, bad)
+}
+
+// Runs checked moves with every crane on its own thread, and gives back the stacks of each.
+pub fn run_parallel(
+    stacks: &Stacks,
+    moves: &[Move],
+    cranes: &mut [Box<dyn Crane + Send>],
+) -> Vec<Stacks> {
+    thread::scope(|scope| {
+        let handles = cranes
+            .iter_mut()
+            .map(|crane| {
+                scope.spawn(move || {
+                    let mut stacks = stacks.clone();
+                    for &(amount, from, to) in moves {
+                        crane.apply(&mut stacks, amount, from - 1, to - 1);
+                    }
+                    stacks
+                })
+            })
+            .collect::<Vec<_>>();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    })
+}
+
+#[test]
+fn test_moves() {
+    use crate::crane::parse_crane;
+
+    assert_eq!(parse_move(b"move 12 from 2 to 10"), Some((12, 2, 10)));
+    assert_eq!(parse_move(b"move 1 from 2 to "), None);
+    assert_eq!(parse_move(b"move 1 from 2 to 3 "), None);
+    assert_eq!(parse_move(b"move -1 from 2 to 3"), None);
+
+    let stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+    let lines = [
+        "move 1 from 2 to 1",
+        "move 4 from 1 to 3",
+        "move 3 from 1 to 3",
+        "",
+        "move 2 from 2 to 1",
+        "move 1 from 1 to 2",
+    ];
+    let (moves, bad) = check_moves(&stacks, lines.iter().map(|l| l.as_bytes()));
+    assert_eq!(moves.len(), 4);
+    assert_eq!(bad.len(), 1);
+    assert_eq!((bad[0].index, bad[0].heights.clone()), (1, vec![3, 2, 1]));
+
+    let moves = moves.into_iter().map(|(_, m)| m).collect::<Vec<_>>();
+    let mut cranes = vec![parse_crane("9000").unwrap(), parse_crane("9001").unwrap()];
+    let tops = run_parallel(&stacks, &moves, &mut cranes)
+        .iter()
+        .map(|stacks| {
+            stacks
+                .iter()
+                .map(|s| *s.last().unwrap())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(tops, ["CMZ", "MCD"]);
+}
@@ -0,0 +1,125 @@
+use crate::crane::{Crane, Move, Stacks};
+use std::collections::HashMap;
+
+// Every move takes crates off one stack and puts them on another. So at least as many moves as
+// stacks with crates to take off are needed, and as many as stacks with crates to put on: those
+// where the crates at the bottom that are already right don't make the whole stack.
+fn lower_bound(stacks: &Stacks, target: &Stacks) -> usize {
+    let (mut to_take, mut to_put) = (0, 0);
+    for (stack, target) in stacks.iter().zip(target) {
+        let right = stack.iter().zip(target).take_while(|(a, b)| a == b).count();
+        to_take += usize::from(right < stack.len());
+        to_put += usize::from(right < target.len());
+    }
+    to_take.max(to_put)
+}
+
+struct Search<'a> {
+    target: &'a Stacks,
+    crane: &'a mut dyn Crane,
+    // The most moves left with which each state was already searched in this round.
+    seen: HashMap<Stacks, usize>,
+    path: Vec<Move>,
+}
+
+impl Search<'_> {
+    // Depth-first, giving up on paths that can't reach the target in `left` moves.
+    fn search(&mut self, stacks: &Stacks, left: usize) -> bool {
+        if stacks == self.target {
+            return true;
+        }
+        if lower_bound(stacks, self.target) > left {
+            return false;
+        }
+        match self.seen.get(stacks) {
+            Some(&seen) if seen >= left => return false,
+            _ => self.seen.insert(stacks.clone(), left),
+        };
+
+        for from in 0..stacks.len() {
+            for to in (0..stacks.len()).filter(|&to| to != from) {
+                for amount in 1..=stacks[from].len() {
+                    let mut next = stacks.clone();
+                    self.crane.apply(&mut next, amount, from, to);
+                    self.path.push((amount, from + 1, to + 1));
+                    if self.search(&next, left - 1) {
+                        return true;
+                    }
+                    self.path.pop();
+                }
+            }
+        }
+        false
+    }
+}
+
+// The fewest moves taking the crane from `start` to `target`, found by iterative deepening A*,
+// trying longer and longer sequences up to `max_moves`. The crane must not keep any state from
+// one move to the next.
+pub fn solve(
+    start: &Stacks,
+    target: &Stacks,
+    crane: &mut dyn Crane,
+    max_moves: usize,
+) -> Result<Vec<Move>, String> {
+    let crates = |stacks: &Stacks| {
+        let mut crates = stacks.iter().flatten().copied().collect::<Vec<_>>();
+        crates.sort();
+        crates
+    };
+    if start.len() != target.len() {
+        return Err(format!(
+            "The drawings have {} and {} stacks",
+            start.len(),
+            target.len()
+        ));
+    }
+    if crates(start) != crates(target) {
+        return Err("The drawings don't have the same crates".to_string());
+    }
+
+    let mut search = Search {
+        target,
+        crane,
+        seen: HashMap::new(),
+        path: Vec::new(),
+    };
+    for bound in lower_bound(start, target)..=max_moves {
+        search.seen.clear();
+        if search.search(start, bound) {
+            return Ok(search.path);
+        }
+    }
+    Err(format!("No sequence of at most {} moves", max_moves))
+}
+
+#[test]
+fn test_solve() {
+    use crate::crane::{CrateMover9000, CrateMover9001};
+
+    let start = vec![vec!['A', 'B'], vec![], vec![]];
+    let target = vec![vec![], vec![], vec!['A', 'B']];
+    let moves = solve(&start, &target, &mut CrateMover9001, 5).unwrap();
+    assert_eq!(moves, [(2, 1, 3)]);
+    assert_eq!(
+        solve(&start, &target, &mut CrateMover9000, 5)
+            .unwrap()
+            .len(),
+        2
+    );
+
+    let start = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+    let target = vec![vec!['C'], vec!['M'], vec!['P', 'D', 'N', 'Z']];
+    let moves = solve(&start, &target, &mut CrateMover9000, 4).unwrap();
+    let mut stacks = start.clone();
+    for (amount, from, to) in &moves {
+        CrateMover9000.apply(&mut stacks, *amount, from - 1, to - 1);
+    }
+    assert_eq!(stacks, target);
+    let tops = stacks.iter().filter_map(|s| s.last()).collect::<String>();
+    assert_eq!(tops, "CMZ");
+    // D onto P, then N and Z together, reversed by the crane, then C.
+    assert_eq!(moves.len(), 3);
+
+    assert!(solve(&start, &target, &mut CrateMover9000, 1).is_err());
+}
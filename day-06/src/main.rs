@@ -1,29 +1,44 @@
-use itertools::Itertools;
+use aoc::args::take_option;
+use aoc::input::Source;
+use day_06::marker;
+use std::env;
 use std::error::Error;
-use std::io;
 
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
+fn parse_lengths(lengths: &str) -> Result<Vec<usize>, String> {
+    lengths
+        .split(',')
+        .map(|length| match length.trim().parse() {
+            Ok(length) if length > 0 => Ok(length),
+            _ => Err(format!("Invalid marker length {:?}", length)),
+        })
+        .collect()
 }
 
+// Usage: day-06 [--lengths <n>,...] [<input> | - | --example]
+//
+// The input is streamed, so it can be of any size. --lengths prints the first marker of every
+// length given, found in the same pass as both parts.
 fn main() -> Result<(), Box<dyn Error>> {
-    let input = stdin_lines().next().unwrap().chars().collect::<Vec<char>>();
+    let mut args = env::args().skip(1).collect::<Vec<_>>();
+    let extra = match take_option(&mut args, "--lengths")? {
+        Some(lengths) => parse_lengths(&lengths)?,
+        None => Vec::new(),
+    };
+    let source = Source::from_remaining_args(args)?;
 
-    for (i, chars) in input.windows(4).enumerate() {
-        if chars.iter().unique().count() == 4 {
-            let result = i + 4;
-            println!("part1: {result}");
-            break;
-        }
-    }
+    let lengths = [4, 14].iter().chain(&extra).copied().collect::<Vec<_>>();
+    let markers = marker::find_markers(source.reader()?, &lengths)?;
 
-    for (i, chars) in input.windows(14).enumerate() {
-        if chars.iter().unique().count() == 14 {
-            let result = i + 14;
-            println!("part2: {result}");
-            return Ok(());
+    for (length, marker) in extra.iter().zip(&markers[2..]) {
+        match marker {
+            Some(marker) => println!("Marker of length {}: {}", length, marker),
+            None => println!("No marker of length {}", length),
         }
     }
+    for (part, (length, marker)) in lengths.iter().zip(&markers).take(2).enumerate() {
+        let marker = marker.ok_or_else(|| format!("No marker of length {}", length))?;
+        println!("part{}: {}", part + 1, marker);
+    }
 
     Ok(())
 }
@@ -0,0 +1,98 @@
+use std::io::{self, ErrorKind, Read};
+
+// The last `length` characters, counting every byte and how many of them show up more than once.
+struct Window {
+    length: usize,
+    counts: [u32; 256],
+    repeated: usize,
+    marker: Option<usize>,
+}
+
+impl Window {
+    fn add(&mut self, byte: u8) {
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 2 {
+            self.repeated += 1;
+        }
+    }
+
+    fn remove(&mut self, byte: u8) {
+        if self.counts[byte as usize] == 2 {
+            self.repeated -= 1;
+        }
+        self.counts[byte as usize] -= 1;
+    }
+}
+
+// The first marker of every length: how many characters come up to the end of the first
+// `length` characters in a row that are all different. The stream is read once, up to the end of
+// its first line, keeping only as many characters as the longest length.
+pub fn find_markers(mut reader: impl Read, lengths: &[usize]) -> io::Result<Vec<Option<usize>>> {
+    let mut windows = lengths
+        .iter()
+        .map(|&length| Window {
+            length,
+            counts: [0; 256],
+            repeated: 0,
+            marker: None,
+        })
+        .collect::<Vec<_>>();
+    let mut last = vec![0; lengths.iter().copied().max().unwrap_or(0)];
+
+    let mut buffer = [0; 8192];
+    let mut position = 0;
+    'read: loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        for &byte in &buffer[..read] {
+            if byte == b'\n' || byte == b'\r' {
+                break 'read;
+            }
+
+            let mut searching = false;
+            for window in windows.iter_mut().filter(|w| w.marker.is_none()) {
+                window.add(byte);
+                if position >= window.length {
+                    window.remove(last[(position - window.length) % last.len()]);
+                }
+                if position + 1 >= window.length && window.repeated == 0 {
+                    window.marker = Some(position + 1);
+                } else {
+                    searching = true;
+                }
+            }
+            if !searching {
+                break 'read;
+            }
+
+            let slot = position % last.len();
+            last[slot] = byte;
+            position += 1;
+        }
+    }
+
+    Ok(windows.iter().map(|w| w.marker).collect())
+}
+
+#[test]
+fn test_find_markers() {
+    let examples = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+    for (stream, part1, part2) in examples {
+        let markers = find_markers(stream.as_bytes(), &[4, 14, 1, 40]).unwrap();
+        assert_eq!(markers, [Some(part1), Some(part2), Some(1), None]);
+    }
+
+    let markers = find_markers(&b"abcabc\nabcdef"[..], &[3, 4]).unwrap();
+    assert_eq!(markers, [Some(3), None]);
+}
@@ -1,13 +1,10 @@
+use aoc::input::Input;
 use sscanf::sscanf;
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let lines = stdin_lines();
+    let input = Input::from_args()?;
+    let lines = input.lines();
     let mut sum: i64 = 0;
     let mut levels: Vec<i64> = vec![0];
 
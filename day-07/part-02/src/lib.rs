@@ -0,0 +1,61 @@
+use sscanf::sscanf;
+use std::collections::HashMap;
+
+const DISK_SIZE: i64 = 70000000;
+const SPACE_NEEDED: i64 = 30000000;
+
+// Directories are named by their absolute path, e.g. "/" or "/a/e".
+fn directory_name(path: &[String]) -> String {
+    format!("/{}", path[1..].join("/"))
+}
+
+// The total size of every directory of the terminal output, subdirectories included.
+pub fn directory_sizes<'a>(lines: impl Iterator<Item = &'a str>) -> HashMap<String, i64> {
+    let mut directories: HashMap<String, i64> = HashMap::new();
+    let mut path: Vec<String> = vec![];
+
+    for line in lines {
+        if line == "$ cd .." {
+            let directory_size = *directories.get(&directory_name(&path)).unwrap();
+            path.pop();
+            *directories.get_mut(&directory_name(&path)).unwrap() += directory_size;
+
+            continue;
+        }
+
+        if let Ok(dirname) = sscanf!(line, "$ cd {}", String) {
+            path.push(dirname.clone());
+            directories.insert(directory_name(&path), 0);
+
+            continue;
+        }
+
+        if let Ok((size, _filename)) = sscanf!(line, "{} {}", i64, String) {
+            *directories.get_mut(&directory_name(&path)).unwrap() += size;
+
+            continue;
+        }
+    }
+
+    for _ in 0..path.len() - 1 {
+        let directory_size = *directories.get(&directory_name(&path)).unwrap();
+        path.pop();
+        *directories.get_mut(&directory_name(&path)).unwrap() += directory_size;
+    }
+
+    directories
+}
+
+pub fn small_directories_total(directories: &HashMap<String, i64>) -> i64 {
+    directories.values().filter(|&&size| size <= 100000).sum()
+}
+
+// The size of the smallest directory to delete to make room for the update.
+pub fn directory_to_delete(directories: &HashMap<String, i64>) -> i64 {
+    let total = *directories.get("/").unwrap();
+    let space_to_free = SPACE_NEEDED - (DISK_SIZE - total);
+
+    let mut sizes: Vec<i64> = directories.values().cloned().collect();
+    sizes.sort();
+    *sizes.iter().find(|&&x| x > space_to_free).unwrap()
+}
@@ -1,11 +1,9 @@
+use aoc::cast::Recorder;
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
 use sscanf::sscanf;
 use std::collections::HashSet;
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
 
 fn normalize(n: i32) -> i32 {
     if n < 0 {
@@ -19,7 +17,10 @@ fn normalize(n: i32) -> i32 {
 
 // Solve the Advent of Code 2022 Day 9 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let lines = stdin_lines();
+    let input = Input::from_args()?;
+    let lines = input.lines();
+    let mut tracer = Tracer::from_env(9);
+    let mut recorder = Recorder::from_env();
     let mut head_x = 0;
     let mut head_y = 0;
     let mut tail_x = 0;
@@ -55,6 +56,14 @@ fn main() -> Result<(), Box<dyn Error>> {
                 visited_positions.insert((tail_x, tail_y));
             }
 
+            tracer.record("move", || {
+                json!({
+                    "line": line,
+                    "knots": [[head_x, head_y], [tail_x, tail_y]],
+                    "visited": visited_positions.len(),
+                })
+            });
+
             if head_x < min_x {
                 min_x = head_x;
             }
@@ -68,19 +77,21 @@ fn main() -> Result<(), Box<dyn Error>> {
                 max_y = head_y;
             }
 
+            let mut frame = String::new();
             for y in (min_y..=max_y).rev() {
                 for x in min_x..=max_x {
                     if x == head_x && y == head_y {
-                        print!("H");
+                        frame.push('H');
                     } else if x == tail_x && y == tail_y {
-                        print!("T");
+                        frame.push('T');
                     } else {
-                        print!(".");
+                        frame.push('.');
                     }
                 }
-                println!();
+                frame.push('\n');
             }
-            println!();
+            println!("{}", frame);
+            recorder.frame(&frame);
         }
     }
 
@@ -0,0 +1,49 @@
+use sscanf::sscanf;
+use std::collections::HashSet;
+
+pub fn normalize(n: i32) -> i32 {
+    if n < 0 {
+        return -1;
+    }
+    if n > 0 {
+        return 1;
+    }
+    n
+}
+
+// The direction and number of steps of a line like "R 4".
+pub fn parse_move(line: &str) -> (char, i64) {
+    sscanf!(line, "{char} {i64}").unwrap()
+}
+
+// Moves a knot next to the one before it, if it isn't already. Returns whether it moved.
+pub fn follow(head: (i32, i32), tail: &mut (i32, i32)) -> bool {
+    let is_tail_next_to_head = i32::abs(head.0 - tail.0) <= 1 && i32::abs(head.1 - tail.1) <= 1;
+    if !is_tail_next_to_head {
+        tail.0 += normalize(head.0 - tail.0);
+        tail.1 += normalize(head.1 - tail.1);
+    }
+    !is_tail_next_to_head
+}
+
+// The positions the last knot of a rope of `knots` knots visits, 2 in part 1 and 10 in part 2.
+pub fn tail_visits(moves: &[(char, i64)], knots: usize) -> HashSet<(i32, i32)> {
+    let mut rope = vec![(0, 0); knots];
+    let mut visited_positions = HashSet::from([(0, 0)]);
+    for &(direction, steps) in moves {
+        for _ in 0..steps {
+            match direction {
+                'U' => rope[0].1 += 1,
+                'D' => rope[0].1 -= 1,
+                'R' => rope[0].0 += 1,
+                'L' => rope[0].0 -= 1,
+                _ => panic!("Unknown direction {}", direction),
+            }
+            for i in 1..knots {
+                follow(rope[i - 1], &mut rope[i]);
+            }
+            visited_positions.insert(rope[knots - 1]);
+        }
+    }
+    visited_positions
+}
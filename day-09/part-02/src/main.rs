@@ -1,25 +1,16 @@
-use sscanf::sscanf;
+use aoc::cast::Recorder;
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
+use day_09_2::{follow, parse_move};
 use std::collections::HashSet;
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
-
-fn normalize(n: i32) -> i32 {
-    if n < 0 {
-        return -1;
-    }
-    if n > 0 {
-        return 1;
-    }
-    n
-}
 
 // Solve the Advent of Code 2022 Day 9 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let lines = stdin_lines();
+    let input = Input::from_args()?;
+    let lines = input.lines();
+    let mut tracer = Tracer::from_env(9);
+    let mut recorder = Recorder::from_env();
     let mut min_x = 0;
     let mut max_x = 0;
     let mut min_y = 0;
@@ -43,7 +34,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     for line in lines {
         println!("---\n{}\n", line);
 
-        let (direction, steps) = sscanf!(line, "{char} {i64}").unwrap();
+        let (direction, steps) = parse_move(line);
 
         for _ in 0..steps {
             match direction {
@@ -55,22 +46,20 @@ fn main() -> Result<(), Box<dyn Error>> {
             }
 
             for i in 1..10 {
-                let (head_x, head_y) = rope.get(i - 1).unwrap().clone();
-                let (tail_x, tail_y) = rope.get_mut(i).unwrap();
-
-                let is_tail_next_to_head =
-                    i32::abs(head_x - *tail_x) <= 1 && i32::abs(head_y - *tail_y) <= 1;
-
-                if !is_tail_next_to_head {
-                    *tail_x += normalize(head_x - *tail_x);
-                    *tail_y += normalize(head_y - *tail_y);
-
-                    if i == 9 {
-                        visited_positions.insert((*tail_x, *tail_y));
-                    }
+                let head = rope[i - 1];
+                if follow(head, &mut rope[i]) && i == 9 {
+                    visited_positions.insert(rope[i]);
                 }
             }
 
+            tracer.record("move", || {
+                json!({
+                    "line": line,
+                    "knots": rope.iter().map(|(x, y)| [x, y]).collect::<Vec<_>>(),
+                    "visited": visited_positions.len(),
+                })
+            });
+
             {
                 let (head_x, head_y) = rope.get(0).unwrap();
 
@@ -89,23 +78,25 @@ fn main() -> Result<(), Box<dyn Error>> {
             }
 
             // Print steps.
+            let mut frame = String::new();
             for y in (min_y..=max_y).rev() {
                 for x in min_x..=max_x {
                     let mut found = false;
                     for (n, (tail_x, tail_y)) in rope.iter().enumerate() {
                         if x == *tail_x && y == *tail_y {
-                            print!("{n}");
+                            frame.push_str(&n.to_string());
                             found = true;
                             break;
                         }
                     }
                     if !found {
-                        print!(".");
+                        frame.push('.');
                     }
                 }
-                println!();
+                frame.push('\n');
             }
-            println!();
+            println!("{}", frame);
+            recorder.frame(&frame);
         }
     }
 
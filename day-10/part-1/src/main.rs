@@ -1,17 +1,23 @@
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
 use sscanf::sscanf;
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
 
 enum Instruction {
     AddX(i64),
     Noop,
 }
 
-fn get_next_instruction(lines: &mut impl Iterator<Item = String>) -> Option<Instruction> {
+impl Instruction {
+    fn describe(&self) -> String {
+        match self {
+            Instruction::AddX(argument) => format!("addx {}", argument),
+            Instruction::Noop => "noop".to_string(),
+        }
+    }
+}
+
+fn get_next_instruction<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Option<Instruction> {
     let line = lines.next()?;
 
     if line == "noop" {
@@ -27,7 +33,9 @@ fn get_next_instruction(lines: &mut impl Iterator<Item = String>) -> Option<Inst
 
 // Solve the Advent of Code 2022 Day 10 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let mut lines = stdin_lines();
+    let input = Input::from_args()?;
+    let mut lines = input.lines();
+    let mut tracer = Tracer::from_env(10);
     let mut x = 1;
     let mut pending_instruction: Option<Instruction> = None;
     let mut signal_strength = 0;
@@ -43,6 +51,11 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
 
         if let Some(ref instruction) = pending_instruction {
+            tracer.record(
+                "cycle",
+                || json!({ "cycle": cycle, "x": x, "instruction": instruction.describe() }),
+            );
+
             match instruction {
                 Instruction::AddX(argument) => {
                     x += argument;
@@ -55,6 +68,11 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
 
         if let Some(instruction) = get_next_instruction(&mut lines) {
+            tracer.record(
+                "cycle",
+                || json!({ "cycle": cycle, "x": x, "instruction": instruction.describe() }),
+            );
+
             match instruction {
                 Instruction::AddX(argument) => {
                     pending_instruction = Some(Instruction::AddX(argument));
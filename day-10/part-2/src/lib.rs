@@ -0,0 +1,75 @@
+use sscanf::sscanf;
+
+pub enum Instruction {
+    AddX(i64),
+    Noop,
+}
+
+impl Instruction {
+    pub fn describe(&self) -> String {
+        match self {
+            Instruction::AddX(argument) => format!("addx {}", argument),
+            Instruction::Noop => "noop".to_string(),
+        }
+    }
+}
+
+pub fn get_next_instruction<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Option<Instruction> {
+    let line = lines.next()?;
+
+    if line == "noop" {
+        return Some(Instruction::Noop);
+    }
+
+    if let Ok((_instruction, argument)) = sscanf!(line, "{} {}", String, i64) {
+        return Some(Instruction::AddX(argument));
+    }
+
+    panic!("Invalid instruction: {}", line);
+}
+
+pub fn render_crt(crt: &[Vec<char>]) -> String {
+    let mut screen = String::new();
+    for line in crt {
+        for c in line {
+            screen.push(*c);
+        }
+        screen.push('\n');
+    }
+    screen
+}
+
+// The value of x during every cycle, the first one being cycle 1.
+pub fn x_during_cycles<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<i64> {
+    let mut lines = lines;
+    let mut x = 1;
+    let mut xs = Vec::new();
+    while let Some(instruction) = get_next_instruction(&mut lines) {
+        match instruction {
+            Instruction::AddX(argument) => {
+                xs.extend([x, x]);
+                x += argument;
+            }
+            Instruction::Noop => xs.push(x),
+        }
+    }
+    xs
+}
+
+// Part 1: the sum of the cycle times x during cycles 20, 60, ... 220.
+pub fn signal_strength(xs: &[i64]) -> i64 {
+    (20..=220)
+        .step_by(40)
+        .filter_map(|cycle| xs.get(cycle - 1).map(|x| cycle as i64 * x))
+        .sum()
+}
+
+// Part 2: the 6 rows of the CRT, the sprite being 3 pixels wide around x.
+pub fn draw_crt(xs: &[i64]) -> Vec<Vec<char>> {
+    let mut crt = vec![vec![' '; 40]; 6];
+    for (cycle, x) in xs.iter().enumerate().take(240) {
+        let column = (cycle % 40) as i64;
+        crt[cycle / 40][column as usize] = if (column - x).abs() <= 1 { '#' } else { '.' };
+    }
+    crt
+}
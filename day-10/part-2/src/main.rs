@@ -1,42 +1,15 @@
-use sscanf::sscanf;
+use aoc::cast::Recorder;
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
+use day_10_2::{get_next_instruction, render_crt, Instruction};
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
-
-enum Instruction {
-    AddX(i64),
-    Noop,
-}
-
-fn get_next_instruction(lines: &mut impl Iterator<Item = String>) -> Option<Instruction> {
-    let line = lines.next()?;
-
-    if line == "noop" {
-        return Some(Instruction::Noop);
-    }
-
-    if let Ok((_instruction, argument)) = sscanf!(line, "{} {}", String, i64) {
-        return Some(Instruction::AddX(argument));
-    }
-
-    panic!("Invalid instruction: {}", line);
-}
-
-fn draw_crt(crt: &Vec<Vec<char>>) {
-    for line in crt {
-        for c in line {
-            print!("{}", c);
-        }
-        println!();
-    }
-}
 
 // Solve the Advent of Code 2022 Day 10 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let mut lines = stdin_lines();
+    let input = Input::from_args()?;
+    let mut lines = input.lines();
+    let mut tracer = Tracer::from_env(10);
+    let mut recorder = Recorder::from_env();
     let mut x: i64 = 1;
     let mut pending_instruction: Option<Instruction> = None;
     let mut signal_strength = 0;
@@ -52,7 +25,11 @@ fn main() -> Result<(), Box<dyn Error>> {
             crt[current_line as usize][current_column as usize] = '.';
         }
 
+        let cycle_x = x;
+        let executing;
+
         if let Some(ref instruction) = pending_instruction {
+            executing = instruction.describe();
             match instruction {
                 Instruction::AddX(argument) => {
                     x += argument;
@@ -63,6 +40,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             pending_instruction = None;
         } else {
             if let Some(instruction) = get_next_instruction(&mut lines) {
+                executing = instruction.describe();
                 match instruction {
                     Instruction::AddX(argument) => {
                         pending_instruction = Some(Instruction::AddX(argument));
@@ -78,7 +56,18 @@ fn main() -> Result<(), Box<dyn Error>> {
             }
         }
 
-        draw_crt(&crt);
+        tracer.record("cycle", || {
+            json!({
+                "cycle": cycle + 1,
+                "x": cycle_x,
+                "instruction": executing,
+                "crt": crt.iter().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>(),
+            })
+        });
+
+        let screen = render_crt(&crt);
+        print!("{}", screen);
+        recorder.frame(&screen);
     }
 
     Ok(())
@@ -1,11 +1,8 @@
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
 use itertools::Itertools;
 use sscanf::sscanf;
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
 
 #[derive(Debug)]
 enum Operator {
@@ -32,7 +29,7 @@ struct Monkey {
     inspection_times: i64,
 }
 
-fn get_next_line_debug(lines: &mut impl Iterator<Item = String>) -> Option<String> {
+fn get_next_line_debug<'a>(lines: &mut impl Iterator<Item = &'a str>) -> Option<&'a str> {
     if let Some(line) = lines.next() {
         println!("line: {}", line);
         Some(line)
@@ -41,7 +38,9 @@ fn get_next_line_debug(lines: &mut impl Iterator<Item = String>) -> Option<Strin
     }
 }
 
-fn parse_monkeys(lines: &mut impl Iterator<Item = String>) -> Result<Vec<Monkey>, Box<dyn Error>> {
+fn parse_monkeys<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> Result<Vec<Monkey>, Box<dyn Error>> {
     let mut monkeys = Vec::new();
 
     loop {
@@ -127,8 +126,15 @@ fn compute_worry_level(
 
 // Solve the Advent of Code 2022 Day 11 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let mut lines = stdin_lines();
+    let input = Input::from_args()?;
+    let mut lines = input.lines();
     let mut monkeys = parse_monkeys(&mut lines)?;
+    let mut tracer = Tracer::from_env(11);
+
+    tracer.record(
+        "start",
+        || json!({ "monkeys": monkeys.iter().map(|m| &m.items).collect::<Vec<_>>() }),
+    );
 
     println!("Monkeys: {:#?}", monkeys);
 
@@ -137,7 +143,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
         for monkey_index in 0..monkeys.len() {
             loop {
-                let (new_worry_level, test_divisor, true_monkey_id, false_monkey_id) = {
+                let (item, new_worry_level, test_divisor, true_monkey_id, false_monkey_id) = {
                     let monkey = &mut monkeys[monkey_index];
                     if monkey.items.is_empty() {
                         break;
@@ -162,6 +168,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                     let false_monkey_id = monkey.false_monkey_id as usize;
 
                     (
+                        item,
                         new_worry_level,
                         test_divisor,
                         true_monkey_id,
@@ -171,13 +178,24 @@ fn main() -> Result<(), Box<dyn Error>> {
 
                 // Check if worry level divisible by monkey's test_divisor.
                 // Throw item to other monkey.
-                if new_worry_level % test_divisor == 0 {
+                let target_monkey_id = if new_worry_level % test_divisor == 0 {
                     // If true, throw to monkey.true_monkey_id.
-                    monkeys[true_monkey_id].items.push(new_worry_level);
+                    true_monkey_id
                 } else {
                     // If false, throw to monkey.false_monkey_id.
-                    monkeys[false_monkey_id].items.push(new_worry_level);
-                }
+                    false_monkey_id
+                };
+                monkeys[target_monkey_id].items.push(new_worry_level);
+
+                tracer.record("throw", || {
+                    json!({
+                        "round": round + 1,
+                        "monkey": monkey_index,
+                        "item": item,
+                        "worry": new_worry_level,
+                        "to": target_monkey_id,
+                    })
+                });
             }
         }
     }
@@ -0,0 +1,237 @@
+use aoc::cycle::{Cycle, CycleDetector};
+use itertools::Itertools;
+use sscanf::sscanf;
+use std::error::Error;
+
+#[derive(Debug)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug)]
+pub enum Operand {
+    Old,
+    Value(i64),
+}
+
+// Items keep their id while they are thrown around, along with their worry level.
+#[derive(Debug)]
+pub struct Monkey {
+    pub items: Vec<(usize, i64)>,
+    pub operator: Operator,
+    pub left_operand: Operand,
+    pub right_operand: Operand,
+    pub test_divisor: i64,
+    pub true_monkey_id: i64,
+    pub false_monkey_id: i64,
+    pub inspection_times: i64,
+}
+
+pub fn parse_monkeys<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+) -> Result<Vec<Monkey>, Box<dyn Error>> {
+    let mut monkeys = Vec::new();
+
+    while let Some(line1) = lines.next() {
+        let _monkey_id = sscanf!(line1, "Monkey {}:", i64).unwrap();
+
+        let line2 = lines.next().unwrap();
+        let items_string = sscanf!(line2, "  Starting items: {}", String).unwrap();
+        let first_id = monkeys
+            .iter()
+            .map(|m: &Monkey| m.items.len())
+            .sum::<usize>();
+        let items = items_string
+            .split(", ")
+            .enumerate()
+            .map(|(i, s)| (first_id + i, s.parse::<i64>().unwrap()))
+            .collect();
+
+        let line3 = lines.next().unwrap();
+        let (left_str, operator_str, right_str) =
+            sscanf!(line3, "  Operation: new = {} {} {}", String, String, String).unwrap();
+        let left_operand = match left_str.as_str() {
+            "old" => Operand::Old,
+            _ => Operand::Value(left_str.parse::<i64>().unwrap()),
+        };
+        let right_operand = match right_str.as_str() {
+            "old" => Operand::Old,
+            _ => Operand::Value(right_str.parse::<i64>().unwrap()),
+        };
+        let operator = match operator_str.as_str() {
+            "+" => Operator::Add,
+            "*" => Operator::Multiply,
+            _ => panic!("Unknown operator: {}", operator_str),
+        };
+
+        let line4 = lines.next().unwrap();
+        let test_divisor = sscanf!(line4, "  Test: divisible by {}", i64).unwrap();
+
+        let line5 = lines.next().unwrap();
+        let true_monkey_id = sscanf!(line5, "    If true: throw to monkey {}", i64).unwrap();
+
+        let line6 = lines.next().unwrap();
+        let false_monkey_id = sscanf!(line6, "    If false: throw to monkey {}", i64).unwrap();
+
+        let monkey = Monkey {
+            items,
+            operator,
+            left_operand,
+            right_operand,
+            test_divisor,
+            true_monkey_id,
+            false_monkey_id,
+            inspection_times: 0,
+        };
+
+        monkeys.push(monkey);
+
+        lines.next();
+    }
+    Ok(monkeys)
+}
+
+pub fn compute_worry_level(
+    item: i64,
+    operator: &Operator,
+    left_operand: &Operand,
+    right_operand: &Operand,
+) -> i64 {
+    let left = match left_operand {
+        Operand::Old => item,
+        Operand::Value(v) => *v,
+    };
+    let right = match right_operand {
+        Operand::Old => item,
+        Operand::Value(v) => *v,
+    };
+    match operator {
+        Operator::Add => left + right,
+        Operator::Multiply => left * right,
+    }
+}
+
+// What happens during the rounds, numbered from 1.
+pub enum Event {
+    // A monkey throws an item, `item` being its worry level before the inspection and `worry`
+    // after.
+    Throw {
+        round: usize,
+        monkey: usize,
+        item: i64,
+        worry: i64,
+        to: usize,
+    },
+    // Every item goes round in circles from then on, and the remaining rounds are extrapolated.
+    Cycle {
+        rounds: usize,
+    },
+}
+
+// The two most active monkeys' inspections multiplied, after the rounds. With relief, worry
+// levels are divided by 3 after every inspection as in part 1. Without, they are kept modulo the
+// product of the divisors, which doesn't change any test. Every event is passed to `observe` on
+// the way.
+pub fn monkey_business(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    relief: bool,
+    mut observe: impl FnMut(Event),
+) -> i64 {
+    let common_multiplicator = monkeys.iter().map(|m| m.test_divisor).product::<i64>();
+
+    // Each item moves on its own, and its monkey and worry level at the start of a round decide
+    // the rest of its journey. Once every item is known to go round in circles, the inspections
+    // of the remaining rounds are extrapolated.
+    let item_count = monkeys.iter().map(|m| m.items.len()).sum::<usize>();
+    let mut item_cycles: Vec<Option<Cycle>> = vec![None; item_count];
+    let mut item_detectors = (0..item_count)
+        .map(|_| CycleDetector::new())
+        .collect::<Vec<_>>();
+    let mut item_inspections = vec![vec![0; monkeys.len()]; item_count];
+    // The inspections of every monkey, per item and per round, until the item's cycle is found.
+    let mut item_history = vec![vec![Vec::new(); monkeys.len()]; item_count];
+
+    for round in 0..rounds {
+        for (monkey_index, monkey) in monkeys.iter().enumerate() {
+            for (id, worry) in monkey.items.iter() {
+                if item_cycles[*id].is_none() {
+                    for (history, inspections) in item_history[*id]
+                        .iter_mut()
+                        .zip(item_inspections[*id].iter())
+                    {
+                        history.push(*inspections);
+                    }
+                    item_cycles[*id] = item_detectors[*id].push((monkey_index, *worry));
+                }
+            }
+        }
+
+        if item_cycles.iter().all(|c| c.is_some()) {
+            observe(Event::Cycle { rounds: round });
+
+            for (monkey_index, monkey) in monkeys.iter_mut().enumerate() {
+                monkey.inspection_times = item_cycles
+                    .iter()
+                    .zip(item_history.iter())
+                    .map(|(cycle, history)| {
+                        cycle.unwrap().extrapolate(&history[monkey_index], rounds)
+                    })
+                    .sum();
+            }
+            break;
+        }
+
+        for monkey_index in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[monkey_index].items);
+            for (id, item) in items {
+                let monkey = &mut monkeys[monkey_index];
+                monkey.inspection_times += 1;
+                item_inspections[id][monkey_index] += 1;
+                let mut worry_level = compute_worry_level(
+                    item,
+                    &monkey.operator,
+                    &monkey.left_operand,
+                    &monkey.right_operand,
+                );
+                // Dividing a level reduced modulo the product wouldn't give the same level.
+                if relief {
+                    worry_level /= 3;
+                } else {
+                    worry_level %= common_multiplicator;
+                }
+
+                let target_monkey_id = if worry_level % monkey.test_divisor == 0 {
+                    monkey.true_monkey_id
+                } else {
+                    monkey.false_monkey_id
+                } as usize;
+                monkeys[target_monkey_id].items.push((id, worry_level));
+
+                observe(Event::Throw {
+                    round: round + 1,
+                    monkey: monkey_index,
+                    item,
+                    worry: worry_level,
+                    to: target_monkey_id,
+                });
+            }
+        }
+    }
+
+    monkeys
+        .iter()
+        .map(|m| m.inspection_times)
+        .sorted()
+        .rev()
+        .take(2)
+        .product()
+}
+
+#[test]
+fn test_monkey_business() {
+    let monkeys = || parse_monkeys(&mut include_str!("../test.txt").lines()).unwrap();
+    assert_eq!(monkey_business(monkeys(), 20, true, |_| ()), 10605);
+    assert_eq!(monkey_business(monkeys(), 10000, false, |_| ()), 2713310158);
+}
@@ -0,0 +1,170 @@
+use itertools::Itertools;
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+}
+
+// Heights go from 0 to 25, along with the start and end positions.
+pub fn parse_map<'a>(lines: impl Iterator<Item = &'a str>) -> (Vec<Vec<usize>>, Pos, Pos) {
+    let mut start: Option<Pos> = None;
+    let mut end: Option<Pos> = None;
+
+    let map: Vec<Vec<usize>> = lines
+        .enumerate()
+        .map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    if c == 'S' {
+                        start = Some(Pos { x, y });
+                        return 0;
+                    }
+                    if c == 'E' {
+                        end = Some(Pos { x, y });
+                        return 25;
+                    }
+                    c as usize - 'a' as usize
+                })
+                .collect_vec()
+        })
+        .collect();
+
+    (map, start.unwrap(), end.unwrap())
+}
+
+fn get_walkable_surrounding_pos(map: &[Vec<usize>], pos: &Pos) -> Vec<Pos> {
+    let current_level = map[pos.y][pos.x];
+
+    let mut surrounding = Vec::new();
+    if pos.x > 0 && map[pos.y][pos.x - 1] as i64 >= current_level as i64 - 1 {
+        surrounding.push(Pos {
+            x: pos.x - 1,
+            y: pos.y,
+        });
+    }
+    if pos.x < map[0].len() - 1 && map[pos.y][pos.x + 1] as i64 >= current_level as i64 - 1 {
+        surrounding.push(Pos {
+            x: pos.x + 1,
+            y: pos.y,
+        });
+    }
+    if pos.y > 0 && map[pos.y - 1][pos.x] as i64 >= current_level as i64 - 1 {
+        surrounding.push(Pos {
+            x: pos.x,
+            y: pos.y - 1,
+        });
+    }
+    if pos.y < map.len() - 1 && map[pos.y + 1][pos.x] as i64 >= current_level as i64 - 1 {
+        surrounding.push(Pos {
+            x: pos.x,
+            y: pos.y + 1,
+        });
+    }
+    surrounding
+}
+
+// How far a position is from the end, and where to go next to get there.
+#[derive(Debug)]
+pub struct Node {
+    pub distance: usize,
+    pub previous: Option<Pos>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct State {
+    distance: usize,
+    pos: Pos,
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// The distance from every position to the end, walking backwards from the end since every
+// position can be the start in part 2.
+pub fn distances_to_end(map: &[Vec<usize>], end: Pos) -> HashMap<Pos, Node> {
+    let mut distances: HashMap<Pos, Node> = HashMap::new();
+    let mut visited: HashSet<Pos> = HashSet::new();
+    let mut to_visit: BinaryHeap<State> = BinaryHeap::new();
+
+    to_visit.push(State {
+        distance: 0,
+        pos: end,
+    });
+    distances.insert(
+        end,
+        Node {
+            distance: 0,
+            previous: None,
+        },
+    );
+
+    // Implement Disjkstra's algorithm.
+    while let Some(State { distance, pos }) = to_visit.pop() {
+        if visited.contains(&pos) {
+            continue;
+        }
+        visited.insert(pos);
+
+        let surrounding = get_walkable_surrounding_pos(map, &pos);
+        for next_pos in surrounding {
+            let next_distance = distance + 1;
+            let next_node = Node {
+                distance: next_distance,
+                previous: Some(pos),
+            };
+            if let Some(node) = distances.get(&next_pos) {
+                if node.distance > next_distance {
+                    distances.insert(next_pos, next_node);
+                    to_visit.push(State {
+                        distance: next_distance,
+                        pos: next_pos,
+                    });
+                }
+            } else {
+                distances.insert(next_pos, next_node);
+                to_visit.push(State {
+                    distance: next_distance,
+                    pos: next_pos,
+                });
+            }
+        }
+    }
+
+    distances
+}
+
+pub fn lowest_start_distance(map: &[Vec<usize>], distances: &HashMap<Pos, Node>) -> usize {
+    // Find the smallest distance to the end for map entries that have a value of 0.
+    let positions_with_value_0: Vec<Pos> = map
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &value)| value == 0)
+                .map(|(x, _)| Pos { x, y })
+                .collect_vec()
+        })
+        .collect();
+
+    positions_with_value_0
+        .iter()
+        .filter_map(|pos| distances.get(pos))
+        .map(|node| node.distance)
+        .min()
+        .unwrap()
+}
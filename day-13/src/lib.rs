@@ -0,0 +1,133 @@
+#[derive(Debug, Clone)]
+pub enum Value {
+    Number(i64),
+    List(Vec<Value>),
+}
+
+// The input parameter looks like this: "[1,[],[2,[3,[4,[5,6,7]]]],8,9]"
+pub fn parse_list(input: &str) -> Value {
+    let mut chars = input.chars().collect::<Vec<char>>();
+    chars.pop();
+    chars.remove(0);
+    let mut values = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '[' {
+            let mut depth = 1;
+            let mut j = i + 1;
+            while depth > 0 {
+                if chars[j] == '[' {
+                    depth += 1;
+                } else if chars[j] == ']' {
+                    depth -= 1;
+                }
+                j += 1;
+            }
+            let sublist = chars[i..j].iter().collect::<String>();
+            values.push(parse_list(&sublist));
+            i = j;
+        } else if c == ',' {
+            i += 1;
+        } else {
+            let mut j = i + 1;
+            while j < chars.len() && chars[j] != ',' {
+                j += 1;
+            }
+            let number = chars[i..j].iter().collect::<String>();
+            values.push(Value::Number(number.parse::<i64>().unwrap()));
+            i = j;
+        }
+    }
+
+    Value::List(values)
+}
+
+pub fn compare_lists(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Number(left), Value::Number(right)) => left < right,
+        (Value::List(left), Value::List(right)) => {
+            for (left, right) in left.iter().zip(right.iter()) {
+                let (left, right) = match (left, right) {
+                    (Value::Number(left), Value::List(right)) => (
+                        Value::List(vec![Value::Number(*left)]),
+                        Value::List((*right).clone()),
+                    ),
+                    (Value::List(left), Value::Number(right)) => (
+                        Value::List((*left).clone()),
+                        Value::List(vec![Value::Number(*right)]),
+                    ),
+                    (left, right) => ((*left).clone(), (*right).clone()),
+                };
+
+                if compare_lists(&left, &right) {
+                    return true;
+                } else if compare_lists(&right, &left) {
+                    return false;
+                }
+            }
+            left.len() < right.len()
+        }
+        _ => false,
+    }
+}
+
+// Part 1: the sum of the 1-based indices of the pairs in the right order.
+pub fn right_order_sum<'a>(lines: impl Iterator<Item = &'a str>) -> usize {
+    let mut current_pair = 0;
+    let mut result = 0;
+    let mut lines = lines;
+
+    loop {
+        current_pair += 1;
+
+        let packet1_str = lines.next().unwrap();
+        let packet2_str = lines.next().unwrap();
+
+        let list1 = parse_list(packet1_str);
+        let list2 = parse_list(packet2_str);
+
+        if compare_lists(&list1, &list2) {
+            result += current_pair;
+        }
+
+        if lines.next().is_none() {
+            break;
+        }
+    }
+    result
+}
+
+// Every packet in the right order, the divider packets "[[2]]" and "[[6]]" included.
+pub fn sort_packets<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut packets = lines.filter(|s| !s.is_empty()).collect::<Vec<&str>>();
+
+    packets.push("[[2]]");
+    packets.push("[[6]]");
+
+    packets.sort_by(|a, b| {
+        let list1 = parse_list(a);
+        let list2 = parse_list(b);
+        if compare_lists(&list1, &list2) {
+            std::cmp::Ordering::Less
+        } else {
+            std::cmp::Ordering::Greater
+        }
+    });
+    packets
+}
+
+// Part 2: the 1-based indices of the divider packets multiplied.
+pub fn decoder_key(packets: &[&str]) -> usize {
+    let mut index1 = 0;
+    let mut index2 = 0;
+    for (i, packet) in packets.iter().enumerate() {
+        if *packet == "[[2]]" {
+            index1 = i + 1;
+        } else if *packet == "[[6]]" {
+            index2 = i + 1;
+        }
+    }
+    index1 * index2
+}
@@ -1,16 +1,18 @@
+use aoc::cast::Recorder;
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
 use itertools::Itertools;
 use std::error::Error;
-use std::io;
 use std::{thread, time};
 
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
+fn draw_map(map: &Vec<Vec<char>>) {
+    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+    print!("{}", render_map(map));
 }
 
-fn draw_map(map: &Vec<Vec<char>>) {
+fn render_map(map: &Vec<Vec<char>>) -> String {
     let mut str = String::new();
 
-    str.push_str(&format!("{esc}[2J{esc}[1;1H", esc = 27 as char));
     for (y, row) in map.iter().enumerate() {
         str.push_str(&format!("{y} "));
         for c in row {
@@ -19,13 +21,18 @@ fn draw_map(map: &Vec<Vec<char>>) {
         str.push_str("\n");
     }
 
-    print!("{}", str);
+    str
 }
 
 // Solve the Advent of Code 2022 Day 14 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
+    let input = Input::from_args()?;
+    let mut tracer = Tracer::from_env(14);
+    let mut recorder = Recorder::from_env();
+
     // Parse paths.
-    let paths: Vec<Vec<(i64, i64)>> = stdin_lines()
+    let paths: Vec<Vec<(i64, i64)>> = input
+        .lines()
         .map(|line| {
             line.split(" -> ")
                 .map(|coord| {
@@ -87,13 +94,28 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Draw the map.
     draw_map(&map);
 
+    tracer.record("start", || {
+        let mut rocks = Vec::new();
+        for (y, row) in map.iter().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                if *c == '#' {
+                    rocks.push([x as i64 + min_x, y as i64]);
+                }
+            }
+        }
+        json!({ "rocks": rocks, "floor": null })
+    });
+
     let directions = [(0, 1), (-1, 1), (1, 1)];
     let sleep_time = time::Duration::from_millis(100);
     let mut falling_sand_pos = None;
     let mut num_resting_units = 0;
+    let mut sand_path = Vec::new();
     loop {
         if falling_sand_pos.is_none() {
             falling_sand_pos = Some((500, 0));
+            sand_path.clear();
+            sand_path.push((500, 0));
             continue;
         }
 
@@ -113,6 +135,14 @@ fn main() -> Result<(), Box<dyn Error>> {
             None
         }) {
             if !is_in_bound(pos) {
+                tracer.record("grain", || {
+                    json!({
+                        "unit": num_resting_units + 1,
+                        "path": sand_path.iter().map(|(x, y)| [x, y]).collect::<Vec<_>>(),
+                        "rest": null,
+                    })
+                });
+
                 draw_map(&map);
                 println!("Result: {}", num_resting_units);
                 return Ok(());
@@ -120,10 +150,22 @@ fn main() -> Result<(), Box<dyn Error>> {
             map[sand_pos.1 as usize][(sand_pos.0 - min_x) as usize] = '.';
             map[pos.1 as usize][(pos.0 - min_x) as usize] = '+';
             falling_sand_pos = Some(pos);
+            sand_path.push(pos);
         } else {
             map[sand_pos.1 as usize][(sand_pos.0 - min_x) as usize] = 'o';
             num_resting_units += 1;
             falling_sand_pos = None;
+            if recorder.is_enabled() {
+                recorder.frame(&render_map(&map));
+            }
+
+            tracer.record("grain", || {
+                json!({
+                    "unit": num_resting_units,
+                    "path": sand_path.iter().map(|(x, y)| [x, y]).collect::<Vec<_>>(),
+                    "rest": [sand_pos.0, sand_pos.1],
+                })
+            });
         }
 
         // draw_map(&map);
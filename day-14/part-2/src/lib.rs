@@ -0,0 +1,80 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+
+// The rock paths, as the (x, y) points of every line.
+pub fn parse_paths<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<Vec<(i64, i64)>> {
+    lines
+        .map(|line| {
+            line.split(" -> ")
+                .map(|coord| {
+                    coord
+                        .split(',')
+                        .map(|n| n.parse::<i64>().unwrap())
+                        .collect_tuple::<(i64, i64)>()
+                        .unwrap()
+                })
+                .collect::<Vec<(i64, i64)>>()
+        })
+        .collect()
+}
+
+// The rocks, drawn as '#' along the paths.
+pub fn rock_map(paths: &[Vec<(i64, i64)>]) -> HashMap<(i64, i64), char> {
+    let mut map: HashMap<(i64, i64), char> = HashMap::new();
+
+    for path in paths {
+        // Iterate of path with a window of 2.
+        for (a, b) in path.iter().tuple_windows() {
+            let (x1, y1) = a;
+            let (x2, y2) = b;
+
+            // Draw a line between the two points.
+            if x1 == x2 {
+                // Vertical line.
+                let (y1, y2) = if y1 < y2 { (*y1, *y2) } else { (*y2, *y1) };
+                for y in y1..=y2 {
+                    map.insert((*x1, y), '#');
+                }
+            } else {
+                // Horizontal line.
+                let (x1, x2) = if x1 < x2 { (*x1, *x2) } else { (*x2, *x1) };
+                for x in x1..=x2 {
+                    map.insert((x, *y1), '#');
+                }
+            }
+        }
+    }
+    map
+}
+
+// The units of sand that come to rest, from (500, 0). Without a floor as in part 1, sand falling
+// below the lowest rock stops it. With the floor 2 below the lowest rock as in part 2, the source
+// getting blocked does.
+pub fn resting_sand(paths: &[Vec<(i64, i64)>], floor: bool) -> usize {
+    let mut map = rock_map(paths);
+    let lowest = paths.iter().flatten().map(|n| n.1).max().unwrap();
+    let directions = [(0, 1), (-1, 1), (1, 1)];
+
+    let mut num_resting_units = 0;
+    loop {
+        let mut sand_pos = (500, 0);
+        loop {
+            let next = directions.iter().find_map(|direction| {
+                let pos = (sand_pos.0 + direction.0, sand_pos.1 + direction.1);
+                let blocked = (floor && pos.1 >= lowest + 2) || map.contains_key(&pos);
+                (!blocked).then_some(pos)
+            });
+            match next {
+                Some(_) if !floor && sand_pos.1 > lowest => return num_resting_units,
+                Some(pos) => sand_pos = pos,
+                None => break,
+            }
+        }
+
+        map.insert(sand_pos, 'o');
+        num_resting_units += 1;
+        if sand_pos == (500, 0) {
+            return num_resting_units;
+        }
+    }
+}
@@ -1,20 +1,23 @@
+use aoc::cast::Recorder;
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
+use day_14_2::{parse_paths, rock_map};
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::error::Error;
-use std::io;
 
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
+fn draw_map(map: &HashMap<(i64, i64), char>) {
+    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+    print!("{}", render_map(map));
 }
 
-fn draw_map(map: &HashMap<(i64, i64), char>) {
+fn render_map(map: &HashMap<(i64, i64), char>) -> String {
     // Find the min and max of the map.
     let (min_x, max_x) = map.keys().map(|(x, _)| x).minmax().into_option().unwrap();
     let (_min_y, max_y) = map.keys().map(|(_, y)| y).minmax().into_option().unwrap();
 
     let mut str = String::new();
 
-    str.push_str(&format!("{esc}[2J{esc}[1;1H", esc = 27 as char));
     for y in 0..=*max_y {
         str.push_str(&format!("{y:<10} ", y = y));
         for x in *min_x..=*max_x {
@@ -23,59 +26,35 @@ fn draw_map(map: &HashMap<(i64, i64), char>) {
         str.push_str("\n");
     }
 
-    print!("{}", str);
+    str
 }
 
 // Solve the Advent of Code 2022 Day 14 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    // Parse paths.
-    let paths: Vec<Vec<(i64, i64)>> = stdin_lines()
-        .map(|line| {
-            line.split(" -> ")
-                .map(|coord| {
-                    coord
-                        .split(',')
-                        .map(|n| n.parse::<i64>().unwrap())
-                        .collect_tuple::<(i64, i64)>()
-                        .unwrap()
-                })
-                .collect::<Vec<(i64, i64)>>()
-        })
-        .collect();
+    let input = Input::from_args()?;
+    let mut tracer = Tracer::from_env(14);
+    let mut recorder = Recorder::from_env();
+
+    let paths = parse_paths(input.lines());
 
     let max_y = paths.iter().flat_map(|n| n).map(|n| n.1).max().unwrap() + 2;
 
-    let mut map: HashMap<(i64, i64), char> = HashMap::new();
-
-    for path in paths {
-        // Iterate of path with a window of 2.
-        for (a, b) in path.iter().tuple_windows() {
-            let (x1, y1) = a;
-            let (x2, y2) = b;
-
-            // Draw a line between the two points.
-            if x1 == x2 {
-                // Vertical line.
-                let (y1, y2) = if y1 < y2 { (*y1, *y2) } else { (*y2, *y1) };
-                for y in y1..=y2 {
-                    map.insert((*x1, y), '#');
-                }
-            } else {
-                // Horizontal line.
-                let (x1, x2) = if x1 < x2 { (*x1, *x2) } else { (*x2, *x1) };
-                for x in x1..=x2 {
-                    map.insert((x, *y1), '#');
-                }
-            }
-        }
-    }
+    let mut map = rock_map(&paths);
+
+    tracer.record("start", || {
+        let rocks = map.keys().sorted().map(|(x, y)| [x, y]).collect::<Vec<_>>();
+        json!({ "rocks": rocks, "floor": max_y })
+    });
 
     let directions = [(0, 1), (-1, 1), (1, 1)];
     let mut falling_sand_pos = None;
     let mut num_resting_units = 0;
+    let mut sand_path = Vec::new();
     loop {
         if falling_sand_pos.is_none() {
             falling_sand_pos = Some((500, 0));
+            sand_path.clear();
+            sand_path.push((500, 0));
             continue;
         }
 
@@ -98,11 +77,23 @@ fn main() -> Result<(), Box<dyn Error>> {
             map.insert(pos, '+');
 
             falling_sand_pos = Some(pos);
+            sand_path.push(pos);
         } else {
             map.insert(sand_pos, 'o');
 
             num_resting_units += 1;
             falling_sand_pos = None;
+            if recorder.is_enabled() {
+                recorder.frame(&render_map(&map));
+            }
+
+            tracer.record("grain", || {
+                json!({
+                    "unit": num_resting_units,
+                    "path": sand_path.iter().map(|(x, y)| [x, y]).collect::<Vec<_>>(),
+                    "rest": [sand_pos.0, sand_pos.1],
+                })
+            });
 
             if sand_pos == (500, 0) {
                 draw_map(&map);
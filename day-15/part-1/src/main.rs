@@ -1,13 +1,9 @@
+use aoc::input::Input;
 use itertools::Itertools;
 use sscanf::sscanf;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
 
 #[derive(Hash, Eq, PartialEq, Debug)]
 struct Pos {
@@ -17,7 +13,8 @@ struct Pos {
 
 // Solve the Advent of Code 2022 Day 15 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let lines = stdin_lines();
+    let input = Input::from_args()?;
+    let lines = input.lines();
     let mut sensors_distances: HashMap<Pos, i64> = HashMap::new();
     let mut beacons: HashSet<Pos> = HashSet::new();
 
@@ -0,0 +1,148 @@
+use sscanf::sscanf;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Hash, Eq, PartialEq, Debug)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+// The sensors with the distance to their closest beacon, and the beacons.
+pub fn parse_sensors<'a>(
+    lines: impl Iterator<Item = &'a str>,
+) -> (HashMap<Pos, i64>, HashSet<Pos>) {
+    let mut sensors_distances: HashMap<Pos, i64> = HashMap::new();
+    let mut beacons: HashSet<Pos> = HashSet::new();
+
+    for line in lines {
+        let (sensor_x, sensor_y, beacon_x, beacon_y) = sscanf!(
+            line,
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            i64,
+            i64,
+            i64,
+            i64
+        )
+        .unwrap();
+
+        let manhattan_distance = i64::abs(sensor_x - beacon_x) + i64::abs(sensor_y - beacon_y);
+
+        sensors_distances.insert(
+            Pos {
+                x: sensor_x,
+                y: sensor_y,
+            },
+            manhattan_distance,
+        );
+
+        beacons.insert(Pos {
+            x: beacon_x,
+            y: beacon_y,
+        });
+    }
+    (sensors_distances, beacons)
+}
+
+// Part 1: the positions of the row where no beacon can be, from the ranges the sensors cover on
+// it, merged.
+pub fn no_beacon_count(
+    sensors_distances: &HashMap<Pos, i64>,
+    beacons: &HashSet<Pos>,
+    y: i64,
+) -> i64 {
+    let mut ranges = sensors_distances
+        .iter()
+        .map(|(pos, distance)| (pos.x, distance - i64::abs(pos.y - y)))
+        .filter(|(_, reach)| *reach >= 0)
+        .map(|(x, reach)| (x - reach, x + reach))
+        .collect::<Vec<_>>();
+    ranges.sort();
+
+    let mut count = 0;
+    let mut covered_to = i64::MIN;
+    for (start, end) in ranges {
+        let start = start.max(covered_to + 1);
+        if start <= end {
+            count += end - start + 1;
+            covered_to = end;
+        }
+    }
+    count - beacons.iter().filter(|b| b.y == y).count() as i64
+}
+
+// Part 2: the only position from 0 to `max` on both axes that no sensor covers, as x * 4000000 + y.
+// It has to be right outside the range of a sensor.
+pub fn tuning_frequency(
+    sensors_distances: &HashMap<Pos, i64>,
+    beacons: &HashSet<Pos>,
+    max: i64,
+) -> Option<i64> {
+    let mut beacon_pos_opt = None;
+
+    let is_pos_in_sensor_range_or_is_beacon = |p: &Pos| {
+        if p.x < 0 || p.x > max || p.y < 0 || p.y > max {
+            return true;
+        }
+
+        let is_in_range = sensors_distances.iter().any(|(sensor_pos, distance)| {
+            i64::abs(p.x - sensor_pos.x) + i64::abs(p.y - sensor_pos.y) <= *distance
+        });
+
+        let has_beacon = beacons.contains(p);
+
+        is_in_range || has_beacon
+    };
+
+    for (pos, distance) in sensors_distances.iter() {
+        let dist = distance + 1;
+
+        for x1 in 0..=dist {
+            let y1 = dist - x1;
+            let y2 = -y1;
+            let x2 = -x1;
+
+            let pos1 = Pos {
+                x: pos.x + x1,
+                y: pos.y + y1,
+            };
+
+            let pos2 = Pos {
+                x: pos.x + x2,
+                y: pos.y + y2,
+            };
+
+            let pos3 = Pos {
+                x: pos.x + x1,
+                y: pos.y + y2,
+            };
+
+            let pos4 = Pos {
+                x: pos.x + x2,
+                y: pos.y + y1,
+            };
+
+            if !is_pos_in_sensor_range_or_is_beacon(&pos1) {
+                beacon_pos_opt = Some(pos1);
+                break;
+            }
+
+            if !is_pos_in_sensor_range_or_is_beacon(&pos2) {
+                beacon_pos_opt = Some(pos2);
+                break;
+            }
+
+            if !is_pos_in_sensor_range_or_is_beacon(&pos3) {
+                beacon_pos_opt = Some(pos3);
+                break;
+            }
+
+            if !is_pos_in_sensor_range_or_is_beacon(&pos4) {
+                beacon_pos_opt = Some(pos4);
+                break;
+            }
+        }
+    }
+
+    beacon_pos_opt.map(|beacon_pos| beacon_pos.x * 4000000 + beacon_pos.y)
+}
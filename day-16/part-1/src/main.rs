@@ -1,12 +1,8 @@
+use aoc::input::Input;
 use sscanf::sscanf;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::error::Error;
-use std::io;
-
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
 
 #[derive(Clone, Debug)]
 struct Valve {
@@ -15,8 +11,9 @@ struct Valve {
     connections: Vec<String>,
 }
 
-fn parse_input() -> Vec<Valve> {
-    stdin_lines()
+fn parse_input(input: &Input) -> Vec<Valve> {
+    input
+        .lines()
         .map(|line| {
             println!("{}", line);
             let (name, rate, _, _, _, connections) = sscanf!(
@@ -233,7 +230,8 @@ fn get_best_total_for_action(
 
 // Solve the Advent of Code 2022 Day 16 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let valves = parse_input();
+    let input = Input::from_args()?;
+    let valves = parse_input(&input);
     println!("Valves: {:?}", valves);
 
     let minutes_left = 31;
@@ -0,0 +1,173 @@
+use sscanf::sscanf;
+use std::cmp::min;
+
+#[derive(Clone, Debug)]
+pub struct Valve {
+    pub name: String,
+    pub rate: i64,
+    pub connections: Vec<String>,
+}
+
+pub fn parse_input(text: &str) -> Vec<Valve> {
+    text.lines()
+        .map(|line| {
+            // println!("{}", line);
+            let (name, rate, _, _, _, connections) = sscanf!(
+                line,
+                "Valve {} has flow rate={}; {:/tunnels?/} {:/leads?/} to {:/valves?/} {}",
+                String,
+                i64,
+                String,
+                String,
+                String,
+                String,
+            )
+            .unwrap();
+
+            Valve {
+                name: name.to_string(),
+                rate,
+                connections: connections.split(", ").map(|s| s.to_string()).collect(),
+            }
+        })
+        .collect()
+}
+
+fn floyd_warshall(graph: &mut [Vec<i64>]) {
+    for i in 0..graph.len() {
+        let via = graph[i].clone();
+        for row in graph.iter_mut() {
+            let v2 = row[i];
+            for (distance, next) in row.iter_mut().zip(&via) {
+                *distance = min(*distance, v2 + next);
+            }
+        }
+    }
+}
+
+struct Context {
+    non_broken_valves: Vec<usize>,
+    distances: Vec<Vec<i64>>,
+    rates: Vec<i64>,
+}
+
+// The valves opened along one path, as bits indexed by valve, and the pressure they release.
+#[derive(Clone, Copy, Debug)]
+pub struct Permutation {
+    pub permutation: u64,
+    pub score: i64,
+}
+
+fn find_permutations(
+    ctx: &Context,
+    permutations: &mut Vec<Permutation>,
+    valve: usize,
+    visited: u64,
+    permutation: Permutation,
+    minutes: i64,
+) {
+    let mut new_visited = visited;
+    // Set bit at position 'valve'.
+    new_visited |= 1 << (valve as u64);
+
+    let new_score = permutation.score + (minutes * ctx.rates[valve]);
+
+    let new_permutation = Permutation {
+        permutation: new_visited,
+        score: new_score,
+    };
+
+    permutations.push(new_permutation);
+
+    let next_valves = ctx.distances[valve]
+        .iter()
+        .enumerate()
+        .filter(|(valve, _)| ctx.non_broken_valves.contains(valve));
+
+    for (next_valve, distance) in next_valves {
+        let is_valve_visited = new_visited & (1 << next_valve) != 0;
+        if !is_valve_visited {
+            let new_minutes = minutes - (distance + 1);
+            if new_minutes >= 0 {
+                find_permutations(
+                    ctx,
+                    permutations,
+                    next_valve,
+                    new_visited,
+                    new_permutation,
+                    new_minutes,
+                );
+            }
+        }
+    }
+}
+
+// Every path starting from AA in the given minutes, along with the index of AA.
+pub fn permutations(graph: &[Valve], minutes: i64) -> (Vec<Permutation>, usize) {
+    // Prepare the matrice for floyd warshall algorithm.
+    let mut distances: Vec<Vec<i64>> = graph
+        .iter()
+        .map(|v| {
+            graph
+                .iter()
+                .map(|v2| {
+                    if v.name == v2.name {
+                        0
+                    } else if v.connections.contains(&v2.name) {
+                        1
+                    } else {
+                        1_000_000_000
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    // Find the shorted distances from every node to every other.
+    floyd_warshall(&mut distances);
+
+    // Find the index of only the non-broken nodes.
+    let non_broken_valves: Vec<usize> = graph
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.rate > 0)
+        .map(|(i, _)| i)
+        .collect();
+
+    // Find the starting point.
+    let valve_aa = graph.iter().position(|v| v.name == "AA").unwrap();
+
+    // Find all the permutations.
+    let ctx = Context {
+        non_broken_valves,
+        distances,
+        rates: graph.iter().map(|v| v.rate).collect(),
+    };
+    let mut permutations: Vec<Permutation> = Vec::new();
+    let permutation = Permutation {
+        permutation: 0,
+        score: 0,
+    };
+    find_permutations(&ctx, &mut permutations, valve_aa, 0, permutation, minutes);
+
+    (permutations, valve_aa)
+}
+
+// The best score of two paths opening different valves, for ourself and the elephant.
+pub fn best_pair_score(permutations: &[Permutation], valve_aa: usize) -> i64 {
+    // Find every pair of permutations with no bits in common and get their best score.
+    let mut max = 0;
+    for (i, p1) in permutations.iter().enumerate() {
+        for (j, p2) in permutations.iter().enumerate() {
+            if i != j {
+                let common_bits =
+                    (p1.permutation & !(1 << valve_aa)) & (p2.permutation & !(1 << valve_aa));
+                if common_bits == 0 && p1.score + p2.score > max {
+                    max = p1.score + p2.score;
+                }
+            }
+        }
+    }
+
+    max
+}
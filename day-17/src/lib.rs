@@ -0,0 +1,268 @@
+use aoc::cycle::CycleDetector;
+use std::cmp::max;
+
+pub const MAP_WIDTH: i64 = 7;
+pub const MAP_HEIGHT: i64 = 131072 / MAP_WIDTH;
+// How much of the top of the tower is compared when looking for a cycle.
+const TOP_ROWS: i64 = 32;
+
+pub struct Map {
+    data: [u8; 131072],
+    previous_draw: Vec<(i64, i64)>,
+}
+
+impl Map {
+    fn set(&mut self, x: i64, y: i64, value: bool) {
+        let index = y * MAP_WIDTH + x;
+        self.data[index as usize] = value as u8;
+    }
+
+    fn get(&self, x: i64, y: i64) -> bool {
+        let index = y * MAP_WIDTH + x;
+        self.data[index as usize] != 0
+    }
+
+    pub fn render(&self, height: i64) {
+        let h = height.min(MAP_HEIGHT);
+        for y in 0..h {
+            for x in 0..MAP_WIDTH {
+                print!("{}", if self.get(x, y) { '#' } else { '.' });
+            }
+            println!();
+        }
+    }
+
+    // Draw the `rows` highest rows below `top`, the way the tower looks from the side.
+    pub fn render_top(&self, top: i64, rows: i64) -> String {
+        let mut screen = String::new();
+        for y in (0.max(top - rows)..top.min(MAP_HEIGHT)).rev() {
+            screen.push('|');
+            screen.push_str(&self.get_row(y));
+            screen.push_str("|\n");
+        }
+        if top <= rows {
+            screen.push_str("+-------+\n");
+        }
+        screen
+    }
+
+    fn draw_piece(&mut self, x: i64, y: i64, piece: &Piece) {
+        self.previous_draw.clear();
+        for (dy, row) in piece.data.iter().enumerate() {
+            for (dx, value) in row.iter().enumerate() {
+                if *value {
+                    self.set(x + dx as i64, y + dy as i64, true);
+                    self.previous_draw.push((x + dx as i64, y + dy as i64));
+                }
+            }
+        }
+    }
+
+    fn clear_previous_piece(&mut self) {
+        // TODO: Remove allocation?
+        for (x, y) in self.previous_draw.clone() {
+            self.set(x, y, false);
+        }
+        self.previous_draw.clear();
+    }
+
+    fn has_collision(&self, piece: &Piece, x: i64, y: i64) -> bool {
+        for (dy, row) in piece.data.iter().enumerate() {
+            for (dx, value) in row.iter().enumerate() {
+                // let is_in_bounds = x >= 0 && x < MAP_WIDTH && y >= 0 && y < MAP_HEIGHT;
+                let is_in_bounds = x + (dx as i64) >= 0
+                    && x + (dx as i64) < MAP_WIDTH
+                    && y + (dy as i64) >= 0
+                    && y + (dy as i64) < MAP_HEIGHT;
+
+                if *value && (!is_in_bounds || self.get(x + dx as i64, y + dy as i64)) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn get_row(&self, y: i64) -> String {
+        let mut row = String::new();
+        for x in 0..MAP_WIDTH {
+            row.push(if self.get(x, y) { '#' } else { '.' });
+        }
+        row
+    }
+}
+
+pub struct Piece {
+    data: Vec<Vec<bool>>,
+    height: i64,
+}
+
+impl Piece {
+    pub fn cells(&self, x: i64, y: i64) -> Vec<[i64; 2]> {
+        let mut cells = Vec::new();
+        for (dy, row) in self.data.iter().enumerate() {
+            for (dx, value) in row.iter().enumerate() {
+                if *value {
+                    cells.push([x + dx as i64, y + dy as i64]);
+                }
+            }
+        }
+        cells
+    }
+}
+
+// The five rocks, in the order they fall.
+pub fn pieces() -> [Piece; 5] {
+    [
+        // ####
+        Piece {
+            data: vec![vec![true, true, true, true]],
+            height: 1,
+        },
+        // .#.
+        // ###
+        // .#.
+        Piece {
+            data: vec![
+                vec![false, true, false],
+                vec![true, true, true],
+                vec![false, true, false],
+            ],
+            height: 3,
+        },
+        // ..#
+        // ..#
+        // ###
+        Piece {
+            data: vec![
+                vec![true, true, true],
+                vec![false, false, true],
+                vec![false, false, true],
+            ],
+            height: 3,
+        },
+        // #
+        // #
+        // #
+        // #
+        Piece {
+            data: vec![vec![true], vec![true], vec![true], vec![true]],
+            height: 4,
+        },
+        // ##
+        // ##
+        Piece {
+            data: vec![vec![true, true], vec![true, true]],
+            height: 2,
+        },
+    ]
+}
+
+// -1 for a jet pushing left, 1 for one pushing right.
+pub fn parse_jets(line: &str) -> Vec<i64> {
+    line.chars()
+        .map(|c| if c == '<' { -1 } else { 1 })
+        .collect()
+}
+
+// What happens while the tower grows.
+pub enum Event<'a> {
+    // A piece came to rest.
+    Drop {
+        piece: usize,
+        shape: usize,
+        jet: usize,
+        cells: Vec<[i64; 2]>,
+        height: i64,
+        map: &'a Map,
+    },
+    // The tower was found to repeat itself, and this many cycles of it were skipped.
+    Skip {
+        cycles: usize,
+        height: i64,
+    },
+}
+
+// The height of the tower after `total_pieces` pieces, 2022 in part 1 and 1000000000000 in
+// part 2. Every event is passed to `observe` on the way.
+pub fn tower_height(jets: &[i64], total_pieces: usize, mut observe: impl FnMut(Event)) -> i64 {
+    let pieces = pieces();
+    let jet_count = jets.len();
+    let mut jets = jets.iter().copied().enumerate().cycle();
+
+    let mut map = Map {
+        data: [0; 131072],
+        previous_draw: Vec::with_capacity(5),
+    };
+    let mut highest;
+    let mut highest_non_moving = 0;
+    let mut piece_index = 0;
+    let mut next_jet = 0;
+    let mut cycles = CycleDetector::new();
+    let mut heights = vec![0];
+
+    let mut n = 0;
+    while n < total_pieces {
+        // The next piece and jet, along with the top of the tower, decide how the tower grows
+        // from here. Pieces don't fall further than a few rows into the tower.
+        let top = map.data[(0.max(highest_non_moving - TOP_ROWS) * MAP_WIDTH) as usize
+            ..(highest_non_moving * MAP_WIDTH) as usize]
+            .to_vec();
+        if let Some(cycle) = cycles.push((piece_index, next_jet, top)) {
+            let max_cycles = (total_pieces - n) / cycle.period;
+            let skipped_height = cycle.delta(&heights) * max_cycles as i64;
+            highest_non_moving = cycle.extrapolate(&heights, total_pieces);
+
+            observe(Event::Skip {
+                cycles: max_cycles,
+                height: skipped_height,
+            });
+            break;
+        }
+
+        let piece = &pieces[piece_index];
+        let mut x = 2;
+        let mut y = highest_non_moving + 3;
+        map.draw_piece(x, y, piece);
+        highest = y + piece.height;
+
+        loop {
+            let (jet_index, push) = jets.next().unwrap();
+            next_jet = (jet_index + 1) % jet_count;
+
+            map.clear_previous_piece();
+            if !map.has_collision(piece, x + push, y) {
+                x += push;
+                map.draw_piece(x, y, piece);
+            } else {
+                map.draw_piece(x, y, piece);
+            }
+
+            map.clear_previous_piece();
+            if map.has_collision(piece, x, y - 1) {
+                map.draw_piece(x, y, piece);
+                highest_non_moving = max(highest_non_moving, highest);
+
+                observe(Event::Drop {
+                    piece: n,
+                    shape: piece_index,
+                    jet: jet_index,
+                    cells: piece.cells(x, y),
+                    height: highest_non_moving,
+                    map: &map,
+                });
+                break;
+            } else {
+                y -= 1;
+                highest = max(highest_non_moving, highest - 1);
+                map.draw_piece(x, y, piece);
+            }
+        }
+
+        n += 1;
+        piece_index = (piece_index + 1) % pieces.len();
+        heights.push(highest_non_moving);
+    }
+
+    highest_non_moving
+}
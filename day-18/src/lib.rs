@@ -0,0 +1,129 @@
+use sscanf::sscanf;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq)]
+enum BlockType {
+    Lava,
+    OutsideAir,
+}
+
+#[derive(sscanf::FromScanf, Debug, Eq, PartialEq, Hash, Copy, Clone)]
+#[sscanf(format = "{x},{y},{z}")]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+const DIRECTIONS: [Pos; 6] = [
+    Pos { x: 0, y: 1, z: 0 },
+    Pos { x: 0, y: -1, z: 0 },
+    Pos { x: 1, y: 0, z: 0 },
+    Pos { x: -1, y: 0, z: 0 },
+    Pos { x: 0, y: 0, z: 1 },
+    Pos { x: 0, y: 0, z: -1 },
+];
+
+pub fn parse_cubes(text: &str) -> Vec<Pos> {
+    text.lines()
+        .map(|line| sscanf!(line, "{Pos}").unwrap())
+        .collect()
+}
+
+// The faces of the cubes not touching another cube, as a cube and the direction it faces.
+pub fn surface_faces(cubes: &[Pos]) -> Vec<(Pos, Pos)> {
+    let mut blocks = HashMap::new();
+    for pos in cubes {
+        blocks.insert(*pos, BlockType::Lava);
+    }
+
+    let mut faces = Vec::new();
+    for (pos, _) in blocks.iter() {
+        for dir in DIRECTIONS.iter() {
+            let adj = Pos {
+                x: pos.x + dir.x,
+                y: pos.y + dir.y,
+                z: pos.z + dir.z,
+            };
+            if !blocks.contains_key(&adj) {
+                faces.push((*pos, *dir));
+            }
+        }
+    }
+
+    faces
+}
+
+// The faces of the cubes reachable from outside the droplet, air pockets excluded.
+pub fn exterior_faces(cubes: &[Pos]) -> Vec<(Pos, Pos)> {
+    let mut blocks = HashMap::new();
+    for pos in cubes {
+        blocks.insert(*pos, BlockType::Lava);
+    }
+
+    // Get the min and max between x, y, and z of all blocks.
+    let min = blocks
+        .keys()
+        .map(|pos| pos.x.min(pos.y).min(pos.z))
+        .min()
+        .unwrap()
+        - 1;
+    let max = blocks
+        .keys()
+        .map(|pos| pos.x.max(pos.y).max(pos.z))
+        .max()
+        .unwrap()
+        + 1;
+
+    // Flood fill with air all the blocks that are outisde the lava.
+    let mut queue = Vec::new();
+    queue.push(Pos {
+        x: min,
+        y: min,
+        z: min,
+    });
+    while let Some(pos) = queue.pop() {
+        if blocks.contains_key(&pos) {
+            continue;
+        }
+        blocks.insert(pos, BlockType::OutsideAir);
+        for dir in DIRECTIONS.iter() {
+            let adj = Pos {
+                x: pos.x + dir.x,
+                y: pos.y + dir.y,
+                z: pos.z + dir.z,
+            };
+            if adj.x < min
+                || adj.x > max
+                || adj.y < min
+                || adj.y > max
+                || adj.z < min
+                || adj.z > max
+            {
+                continue;
+            }
+            if !blocks.contains_key(&adj) {
+                queue.push(adj);
+            }
+        }
+    }
+
+    let mut faces = Vec::new();
+    for (pos, _) in blocks.iter() {
+        if blocks.get(pos) != Some(&BlockType::Lava) {
+            continue;
+        }
+        for dir in DIRECTIONS.iter() {
+            let adj = Pos {
+                x: pos.x + dir.x,
+                y: pos.y + dir.y,
+                z: pos.z + dir.z,
+            };
+            if blocks.get(&adj) == Some(&BlockType::OutsideAir) {
+                faces.push((*pos, *dir));
+            }
+        }
+    }
+
+    faces
+}
@@ -1,6 +1,6 @@
+use aoc::input::Input;
 use sscanf::sscanf;
 use std::error::Error;
-use std::io;
 
 #[derive(sscanf::FromScanf, Debug, Eq, PartialEq, Hash, Copy, Clone)]
 #[sscanf(
@@ -16,10 +16,6 @@ struct Blueprint {
     geode_robot_obsidian_cost: i32,
 }
 
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
-
 #[derive(Debug, Copy, Clone)]
 struct Stat {
     ore: i32,
@@ -131,7 +127,9 @@ fn go(ctx: &Context, blueprint: &Blueprint, previous_stat: Stat, minutes: i32) -
 
 // Solve the Advent of Code 2022 Day 19 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let blueprints = stdin_lines()
+    let input = Input::from_args()?;
+    let blueprints = input
+        .lines()
         .map(|line| sscanf!(line, "{Blueprint}"))
         .collect::<Result<Vec<_>, _>>()?;
     println!("{:#?}", blueprints);
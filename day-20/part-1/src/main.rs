@@ -1,6 +1,7 @@
+use aoc::input::Input;
+use aoc::trace::{json, Tracer};
 use sscanf::sscanf;
 use std::error::Error;
-use std::io;
 
 #[derive(Debug, Clone, Copy)]
 struct Item {
@@ -8,10 +9,6 @@ struct Item {
     value: i32,
 }
 
-fn stdin_lines() -> impl Iterator<Item = String> {
-    io::stdin().lines().map(|l| l.unwrap())
-}
-
 fn mod_floor(a: i32, base: i32) -> i32 {
     ((a % base) + base) % base
 }
@@ -33,7 +30,10 @@ fn test_mod_floor() {
 
 // Solve the Advent of Code 2022 Day 20 puzzle.
 fn main() -> Result<(), Box<dyn Error>> {
-    let numbers: Vec<i32> = stdin_lines()
+    let input = Input::from_args()?;
+    let mut tracer = Tracer::from_env(20);
+    let numbers: Vec<i32> = input
+        .lines()
         .map(|line| sscanf!(line, "{i32}"))
         .collect::<Result<Vec<_>, _>>()?;
 
@@ -50,6 +50,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     let mut list2 = list1.clone();
 
+    tracer.record(
+        "start",
+        || json!({ "values": list1.iter().map(|item| item.value).collect::<Vec<_>>() }),
+    );
+
     for item in list1.iter() {
         // println!("item: {:#?}", item);
 
@@ -58,6 +63,16 @@ fn main() -> Result<(), Box<dyn Error>> {
         let abs_value = i32::abs(item.value);
         let sign = if item.value < 0 { -1 } else { 1 };
 
+        tracer.record("mix", || {
+            json!({
+                "round": 1,
+                "id": item.id,
+                "value": item.value,
+                "from": index,
+                "shift": sign * abs_value,
+            })
+        });
+
         for n in 0..abs_value {
             let i = mod_floor(index as i32 + sign * n, list2.len() as i32);
             let j = mod_floor(index as i32 + sign * (n + 1), list2.len() as i32);
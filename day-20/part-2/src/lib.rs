@@ -0,0 +1,80 @@
+use sscanf::sscanf;
+
+pub const DECRYPTION_KEY: i64 = 811589153;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Item {
+    pub id: i64,
+    pub value: i64,
+}
+
+pub fn mod_floor(a: i64, base: i64) -> i64 {
+    ((a % base) + base) % base
+}
+
+#[test]
+fn test_mod_floor() {
+    assert_eq!(mod_floor(0, 3), 0);
+    assert_eq!(mod_floor(1, 3), 1);
+    assert_eq!(mod_floor(2, 3), 2);
+    assert_eq!(mod_floor(3, 3), 0);
+    assert_eq!(mod_floor(4, 3), 1);
+    assert_eq!(mod_floor(5, 3), 2);
+    assert_eq!(mod_floor(-1, 3), 2);
+    assert_eq!(mod_floor(-2, 3), 1);
+    assert_eq!(mod_floor(-3, 3), 0);
+    assert_eq!(mod_floor(-4, 3), 2);
+    assert_eq!(mod_floor(-5, 3), 1);
+}
+
+pub fn parse_numbers<'a>(lines: impl Iterator<Item = &'a str>) -> Result<Vec<i64>, sscanf::Error> {
+    lines.map(|line| sscanf!(line, "{i64}")).collect()
+}
+
+// The numbers times the key, mixed the number of rounds, 1 with a key of 1 in part 1 and 10 with
+// `DECRYPTION_KEY` in part 2. Every move is passed to `observe` with the round, the item, where
+// it was and how far it moves.
+pub fn mix(
+    numbers: &[i64],
+    key: i64,
+    rounds: usize,
+    mut observe: impl FnMut(usize, &Item, usize, i64),
+) -> Vec<Item> {
+    let list1 = numbers
+        .iter()
+        .enumerate()
+        .map(|(i, &n)| Item {
+            id: i as i64,
+            value: n * key,
+        })
+        .collect::<Vec<_>>();
+
+    let mut list2 = list1.clone();
+    let list2_len = list2.len();
+
+    for round in 0..rounds {
+        for item in list1.iter() {
+            let index = list2.iter().position(|x| x.id == item.id).unwrap();
+
+            let value: i64 = item.value % (list2_len as i64 - 1);
+            let abs_value: i64 = i64::abs(value);
+            let sign: i64 = if item.value < 0 { -1 } else { 1 };
+
+            observe(round, item, index, sign * abs_value);
+
+            for n in 0..abs_value {
+                let i = mod_floor(index as i64 + sign * n, list2_len as i64);
+                let j = mod_floor(index as i64 + sign * (n + 1), list2_len as i64);
+
+                list2.swap(i as usize, j as usize);
+            }
+        }
+    }
+    list2
+}
+
+// The numbers 1000, 2000 and 3000 after the 0, whose sum is the answer.
+pub fn grove_coordinates(mixed: &[Item]) -> [i64; 3] {
+    let index = mixed.iter().position(|x| x.value == 0).unwrap();
+    [1000, 2000, 3000].map(|offset| mixed[(index + offset) % mixed.len()].value)
+}
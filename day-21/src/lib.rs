@@ -0,0 +1,109 @@
+use sscanf::sscanf;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub key1: String,
+    pub key2: String,
+    pub operand: char,
+}
+
+#[derive(Debug, Clone)]
+pub enum Statement {
+    Operation(Operation),
+    Value(i64),
+}
+
+pub fn get_value(items: &HashMap<String, Statement>, key: &str) -> i64 {
+    let item = items.get(key);
+
+    if let Some(Statement::Value(value)) = item {
+        return *value;
+    } else if let Some(Statement::Operation(op)) = item {
+        let value1 = get_value(items, &op.key1);
+        let value2 = get_value(items, &op.key2);
+
+        return match op.operand {
+            '+' => value1 + value2,
+            '-' => value1 - value2,
+            '*' => value1 * value2,
+            '/' => value1 / value2,
+            _ => unreachable!(),
+        };
+    }
+
+    unreachable!();
+}
+
+pub fn parse_monkeys<'a>(lines: impl Iterator<Item = &'a str>) -> HashMap<String, Statement> {
+    lines
+        .map(|line| {
+            let (key, statement) = sscanf!(line, "{}: {}", String, String).unwrap();
+
+            // Try to parse statement as i64.
+            if let Ok(value) = statement.parse::<i64>() {
+                (key, Statement::Value(value))
+            } else {
+                let (key1, operand, key2) =
+                    sscanf!(statement, "{} {} {}", String, char, String).unwrap();
+                (
+                    key,
+                    Statement::Operation(Operation {
+                        key1,
+                        key2,
+                        operand,
+                    }),
+                )
+            }
+        })
+        .collect()
+}
+
+// The number to yell as `humn` so that both sides of `root` are equal.
+pub fn humn_value(items: &HashMap<String, Statement>) -> i64 {
+    let mut items = items.clone();
+    let root = items.get("root").cloned().unwrap();
+    let op = if let Statement::Operation(op) = root {
+        op
+    } else {
+        unreachable!();
+    };
+
+    let mut humn_value = 0;
+    let mut increment = 1_000_000_000;
+    let mut is_incrementing = true;
+    loop {
+        items.insert("humn".to_owned(), Statement::Value(humn_value));
+
+        let value1 = get_value(&items, &op.key1);
+        let value2 = get_value(&items, &op.key2);
+        let sub = value1 - value2;
+
+        if value1 == value2 {
+            break;
+        }
+
+        // Doing a dichotomic search.
+        if is_incrementing {
+            if sub > 0 {
+                humn_value += increment;
+            } else {
+                is_incrementing = false;
+                increment /= 2;
+                humn_value -= increment;
+            }
+        } else {
+            if sub < 0 {
+                humn_value -= increment;
+            } else {
+                is_incrementing = true;
+                increment /= 2;
+                humn_value += increment;
+            }
+        }
+
+        humn_value -= 1;
+    }
+
+    humn_value
+}